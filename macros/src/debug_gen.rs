@@ -1,121 +1,343 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use proc_macro2::{Literal, Span};
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse, parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Ident};
+use syn::{
+    parse::{self, Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Field, Fields, GenericParam, Ident, Index, LitStr, Path, Token,
+    WherePredicate,
+};
+
+/// An argument of the `#[udebug(..)]` attribute
+enum Arg {
+    /// Container: replaces the generated bounds
+    Bound(LitStr),
+    /// Field: written as hex number with prefix like `{:#x}`
+    Hex,
+    /// Field: the length of a redacted value is written
+    Len,
+    /// Field: a placeholder is written instead of the value
+    Redact(Option<LitStr>),
+    /// Field and variant: written with another name
+    Rename(LitStr),
+    /// Field: not written
+    Skip,
+    /// Container: a newtype is written like its only field
+    Transparent,
+    /// Field: written by the function `path(&value, f)`
+    With(Path),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let key: Ident = input.parse()?;
+        let arg = match key.to_string().as_str() {
+            "bound" => {
+                let _: Token![=] = input.parse()?;
+                Arg::Bound(input.parse()?)
+            }
+            "hex" => Arg::Hex,
+            "len" => Arg::Len,
+            "redact" => {
+                if input.peek(Token![=]) {
+                    let _: Token![=] = input.parse()?;
+                    Arg::Redact(Some(input.parse()?))
+                } else {
+                    Arg::Redact(None)
+                }
+            }
+            "rename" => {
+                let _: Token![=] = input.parse()?;
+                Arg::Rename(input.parse()?)
+            }
+            "skip" => Arg::Skip,
+            "transparent" => Arg::Transparent,
+            "with" => {
+                let _: Token![=] = input.parse()?;
+                Arg::With(input.parse()?)
+            }
+            _ => return Err(parse::Error::new(key.span(), "unknown `udebug` argument")),
+        };
+        Ok(arg)
+    }
+}
+
+/// The arguments of all `#[udebug(..)]` attributes of an item
+#[derive(Default)]
+struct Attrs {
+    bound: Option<LitStr>,
+    hex: bool,
+    len: bool,
+    redact: Option<Option<LitStr>>,
+    rename: Option<LitStr>,
+    skip: bool,
+    transparent: bool,
+    with: Option<Path>,
+}
+
+/// Where the attribute is placed
+#[derive(Clone, Copy, PartialEq)]
+enum Position {
+    Container,
+    Variant,
+    Field,
+}
+
+impl Attrs {
+    fn parse(attrs: &[Attribute], position: Position) -> parse::Result<Self> {
+        let mut parsed = Attrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("udebug")) {
+            let args = attr.parse_args_with(Punctuated::<Arg, Token![,]>::parse_terminated)?;
+            for arg in args {
+                let allowed = match arg {
+                    Arg::Bound(_) | Arg::Transparent => position == Position::Container,
+                    Arg::Rename(_) => position != Position::Container,
+                    Arg::Hex | Arg::Len | Arg::Redact(_) | Arg::Skip | Arg::With(_) => {
+                        position == Position::Field
+                    }
+                };
+                if !allowed {
+                    return Err(parse::Error::new(
+                        attr.span(),
+                        match position {
+                            Position::Container => "argument not allowed on a struct or enum",
+                            Position::Variant => "argument not allowed on a variant",
+                            Position::Field => "argument not allowed on a field",
+                        },
+                    ));
+                }
+
+                match arg {
+                    Arg::Bound(bound) => parsed.bound = Some(bound),
+                    Arg::Hex => parsed.hex = true,
+                    Arg::Len => parsed.len = true,
+                    Arg::Redact(placeholder) => parsed.redact = Some(placeholder),
+                    Arg::Rename(name) => parsed.rename = Some(name),
+                    Arg::Skip => parsed.skip = true,
+                    Arg::Transparent => parsed.transparent = true,
+                    Arg::With(path) => parsed.with = Some(path),
+                }
+            }
+
+            let kinds = [parsed.hex, parsed.redact.is_some(), parsed.with.is_some()];
+            if kinds.iter().filter(|&&kind| kind).count() > 1 {
+                return Err(parse::Error::new(
+                    attr.span(),
+                    "`hex`, `redact` and `with` cannot be combined",
+                ));
+            }
+            if parsed.len && parsed.redact.is_none() {
+                return Err(parse::Error::new(attr.span(), "`len` requires `redact`"));
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// A field which is written, `value` is a reference to it
+struct DebugField {
+    name: String,
+    value: TokenStream2,
+    attrs: Attrs,
+}
+
+impl DebugField {
+    /// Expression of type `Result`, which writes the value to `f`
+    fn call(&self) -> TokenStream2 {
+        let value = &self.value;
+        if let Some(placeholder) = &self.attrs.redact {
+            let mut redacted = quote!(efmt::Redacted::new(#value));
+            if let Some(placeholder) = placeholder {
+                redacted = quote!(#redacted.placeholder(#placeholder));
+            }
+            if self.attrs.len {
+                redacted = quote!(#redacted.with_len());
+            }
+            quote!(efmt::uDebug::fmt(&#redacted, f))
+        } else if let Some(path) = &self.attrs.with {
+            quote!(#path(#value, f))
+        } else if self.attrs.hex {
+            quote!(efmt::uDisplayHex::fmt_hex(#value, f, true, 'x', efmt::Padding::Usual(0), ' '))
+        } else {
+            quote!(efmt::uDebug::fmt(#value, f))
+        }
+    }
+
+    /// Builder method of `DebugStruct` or `DebugTuple`
+    fn method(&self, named: bool) -> TokenStream2 {
+        let name = &self.name;
+        let value = &self.value;
+        let plain = self.attrs.with.is_none() && !self.attrs.hex && self.attrs.redact.is_none();
+        match (named, plain) {
+            (true, true) => quote!(field(#name, #value)?),
+            (false, true) => quote!(field(#value)?),
+            (true, false) => {
+                let call = self.call();
+                quote!(field_with(#name, |f| #call)?)
+            }
+            (false, false) => {
+                let call = self.call();
+                quote!(field_with(|f| #call)?)
+            }
+        }
+    }
+}
+
+/// The fields which are not skipped, `value` creates the reference to the field
+fn debug_fields(
+    fields: &Fields,
+    value: impl Fn(usize, &Field) -> TokenStream2,
+) -> parse::Result<Vec<DebugField>> {
+    let mut debug_fields = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let attrs = Attrs::parse(&field.attrs, Position::Field)?;
+        if attrs.skip {
+            continue;
+        }
+        let name = match (&attrs.rename, &field.ident) {
+            (Some(name), _) => name.value(),
+            (None, Some(ident)) => ident.to_string(),
+            (None, None) => String::new(),
+        };
+        debug_fields.push(DebugField {
+            name,
+            value: value(i, field),
+            attrs,
+        });
+    }
+    Ok(debug_fields)
+}
+
+/// Writes the fields like `debug_struct` or `debug_tuple`
+fn body(name: &str, fields: &Fields, debug_fields: &[DebugField]) -> TokenStream2 {
+    match fields {
+        Fields::Named(_) => {
+            let methods = debug_fields.iter().map(|field| field.method(true));
+            quote!(f.debug_struct(#name)?#(.#methods)*.finish())
+        }
+        Fields::Unnamed(_) => {
+            let methods = debug_fields.iter().map(|field| field.method(false));
+            quote!(f.debug_tuple(#name)?#(.#methods)*.finish())
+        }
+        Fields::Unit => quote!(f.write_str(#name)),
+    }
+}
 
 pub fn debug_gen(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    match debug(input) {
+        Err(e) => e.to_compile_error().into(),
+        Ok(ts) => ts.into(),
+    }
+}
+
+fn debug(input: DeriveInput) -> parse::Result<TokenStream2> {
+    let attrs = Attrs::parse(&input.attrs, Position::Container)?;
+
     let mut generics = input.generics;
 
-    for param in &mut generics.params {
-        if let GenericParam::Type(type_param) = param {
-            type_param.bounds.push(parse_quote!(efmt::uDebug));
+    match &attrs.bound {
+        Some(bound) => {
+            let predicates =
+                bound.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        None => {
+            for param in &mut generics.params {
+                if let GenericParam::Type(type_param) = param {
+                    type_param.bounds.push(parse_quote!(efmt::uDebug));
+                }
+            }
         }
     }
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let ident = &input.ident;
-    let ts = match input.data {
+    let body = match input.data {
         Data::Struct(data) => {
-            let ident_s = ident.to_string();
-
-            let body = match data.fields {
-                Fields::Named(fields) => {
-                    let fields = fields
-                        .named
-                        .iter()
-                        .map(|field| {
-                            let ident = field.ident.as_ref().expect("UNREACHABLE");
-                            let name = ident.to_string();
-
-                            quote!(field(#name, &self.#ident)?)
-                        })
-                        .collect::<Vec<_>>();
-
-                    quote!(f.debug_struct(#ident_s)?#(.#fields)*.finish())
-                }
-
-                Fields::Unnamed(fields) => {
-                    let fields = (0..fields.unnamed.len())
-                        .map(|i| {
-                            let i = Literal::u64_unsuffixed(i as u64);
-
-                            quote!(field(&self.#i)?)
-                        })
-                        .collect::<Vec<_>>();
-
-                    quote!(f.debug_tuple(#ident_s)?#(.#fields)*.finish())
+            let debug_fields = debug_fields(&data.fields, |i, field| match &field.ident {
+                Some(ident) => quote!(&self.#ident),
+                None => {
+                    let i = Index::from(i);
+                    quote!(&self.#i)
                 }
+            })?;
 
-                Fields::Unit => quote!(f.write_str(#ident_s)),
-            };
-
-            quote!(
-                impl #impl_generics efmt::uDebug for #ident #ty_generics #where_clause {
-                    fn fmt<W>(&self, f: &mut efmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
-                    where
-                        W: efmt::uWrite + ?Sized,
-                    {
-                        #body
+            if attrs.transparent {
+                match debug_fields.as_slice() {
+                    [field] => field.call(),
+                    _ => {
+                        return Err(parse::Error::new(
+                            ident.span(),
+                            "`transparent` requires exactly one field, which is not skipped",
+                        ))
                     }
                 }
-
-            )
+            } else {
+                body(&ident.to_string(), &data.fields, &debug_fields)
+            }
         }
 
         Data::Enum(data) => {
-            let arms = data
-                .variants
-                .iter()
-                .map(|var| {
-                    let variant = &var.ident;
-                    let variant_s = variant.to_string();
-
-                    match &var.fields {
-                        Fields::Named(fields) => {
-                            let mut pats = Vec::with_capacity(fields.named.len());
-                            let mut methods = Vec::with_capacity(fields.named.len());
-                            for field in &fields.named {
-                                let ident = field.ident.as_ref().unwrap();
-                                let ident_s = ident.to_string();
-
-                                pats.push(quote!(#ident));
-                                methods.push(quote!(field(#ident_s, #ident)?));
-                            }
-
-                            quote!(
-                                #ident::#variant { #(#pats),* } => {
-                                    f.debug_struct(#variant_s)?#(.#methods)*.finish()
-                                }
-                            )
-                        }
+            if attrs.transparent {
+                return Err(parse::Error::new(
+                    ident.span(),
+                    "`transparent` is not supported for enums",
+                ));
+            }
 
-                        Fields::Unnamed(fields) => {
-                            let pats = &(0..fields.unnamed.len())
-                                .map(|i| Ident::new(&format!("_{}", i), Span::call_site()))
-                                .collect::<Vec<_>>();
+            let mut arms = Vec::with_capacity(data.variants.len());
+            for var in &data.variants {
+                let variant = &var.ident;
+                let name = match Attrs::parse(&var.attrs, Position::Variant)?.rename {
+                    Some(name) => name.value(),
+                    None => variant.to_string(),
+                };
+
+                let debug_fields = debug_fields(&var.fields, |i, field| match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let ident = Ident::new(&format!("_{}", i), Span::call_site());
+                        quote!(#ident)
+                    }
+                })?;
 
-                            quote!(
-                                #ident::#variant(#(#pats),*) => {
-                                    f.debug_tuple(#variant_s)?#(.field(#pats)?)*.finish()
-                                }
-                            )
+                // skipped fields are not bound
+                let mut pats = vec![];
+                for (i, field) in var.fields.iter().enumerate() {
+                    let skip = Attrs::parse(&field.attrs, Position::Field)?.skip;
+                    pats.push(match (&field.ident, skip) {
+                        (Some(ident), false) => quote!(#ident),
+                        (Some(ident), true) => quote!(#ident: _),
+                        (None, false) => {
+                            let ident = Ident::new(&format!("_{}", i), Span::call_site());
+                            quote!(#ident)
                         }
+                        (None, true) => quote!(_),
+                    });
+                }
+
+                let pat = match &var.fields {
+                    Fields::Named(_) => quote!(#ident::#variant { #(#pats),* }),
+                    Fields::Unnamed(_) => quote!(#ident::#variant(#(#pats),*)),
+                    Fields::Unit => quote!(#ident::#variant),
+                };
+                let body = body(&name, &var.fields, &debug_fields);
 
-                        Fields::Unit => quote!(
-                            #ident::#variant => {
-                                f.write_str(#variant_s)
-                            }
-                        ),
+                arms.push(quote!(
+                    #pat => {
+                        #body
                     }
-                })
-                .collect::<Vec<_>>();
+                ));
+            }
 
-            let body = if arms.is_empty() {
+            if arms.is_empty() {
                 // Debug's implementation uses `::core::intrinsics::unreachable()`
                 quote!(unsafe { core::unreachable!() })
             } else {
@@ -124,26 +346,25 @@ pub fn debug_gen(input: TokenStream) -> TokenStream {
                         #(#arms),*
                     }
                 )
-            };
-
-            quote!(
-                impl #impl_generics efmt::uDebug for #ident #ty_generics #where_clause {
-                    fn fmt<W>(&self, f: &mut efmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
-                        where
-                        W: efmt::uWrite + ?Sized,
-                    {
-                        #body
-                    }
-                }
-            )
+            }
         }
 
         Data::Union(..) => {
-            return parse::Error::new(Span::call_site(), "this trait cannot be derived for unions")
-                .to_compile_error()
-                .into();
+            return Err(parse::Error::new(
+                Span::call_site(),
+                "this trait cannot be derived for unions",
+            ));
         }
     };
 
-    ts.into()
+    Ok(quote!(
+        impl #impl_generics efmt::uDebug for #ident #ty_generics #where_clause {
+            fn fmt<W>(&self, f: &mut efmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+            where
+                W: efmt::uWrite + ?Sized,
+            {
+                #body
+            }
+        }
+    ))
 }
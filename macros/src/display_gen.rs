@@ -0,0 +1,177 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse, parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields,
+    GenericParam, Ident, Index,
+};
+
+use crate::write_gen::{format_gen, Format};
+
+pub fn display_gen(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match display(input) {
+        Err(e) => e.to_compile_error().into(),
+        Ok(ts) => ts.into(),
+    }
+}
+
+fn display(input: DeriveInput) -> parse::Result<TokenStream2> {
+    let mut generics = input.generics;
+
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(parse_quote!(efmt::uDisplay));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // hygienic, so that a field `f`, which is bound in an enum variant, does not shadow it
+    let formatter = Ident::new("f", Span::mixed_site());
+
+    let ident = &input.ident;
+    let format = format_attr(&input.attrs)?;
+    let body = match input.data {
+        Data::Struct(data) => {
+            let fields = data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| match &field.ident {
+                    Some(ident) => (ident.to_string(), quote!(self.#ident)),
+                    None => {
+                        let i = Index::from(i);
+                        (i.index.to_string(), quote!(self.#i))
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            body(&formatter, format, ident, &data.fields, &fields)?
+        }
+
+        Data::Enum(data) => {
+            if let Some(format) = format {
+                return Err(parse::Error::new(
+                    format.span(),
+                    "`#[udisplay(..)]` is expected on the variants of an enum",
+                ));
+            }
+
+            let mut arms = Vec::with_capacity(data.variants.len());
+            for var in &data.variants {
+                let variant = &var.ident;
+                let (pat, fields) = match &var.fields {
+                    Fields::Named(fields) => {
+                        let idents = fields
+                            .named
+                            .iter()
+                            .map(|field| field.ident.as_ref().expect("UNREACHABLE"))
+                            .collect::<Vec<_>>();
+                        let fields = idents
+                            .iter()
+                            .map(|ident| (ident.to_string(), quote!(*#ident)))
+                            .collect::<Vec<_>>();
+                        (quote!(#ident::#variant { #(#idents),* }), fields)
+                    }
+
+                    Fields::Unnamed(fields) => {
+                        let pats = (0..fields.unnamed.len())
+                            .map(|i| Ident::new(&format!("_{}", i), Span::call_site()))
+                            .collect::<Vec<_>>();
+                        let fields = pats
+                            .iter()
+                            .enumerate()
+                            .map(|(i, pat)| (i.to_string(), quote!(*#pat)))
+                            .collect::<Vec<_>>();
+                        (quote!(#ident::#variant(#(#pats),*)), fields)
+                    }
+
+                    Fields::Unit => (quote!(#ident::#variant), vec![]),
+                };
+
+                let body = body(
+                    &formatter,
+                    format_attr(&var.attrs)?,
+                    variant,
+                    &var.fields,
+                    &fields,
+                )?;
+                arms.push(quote!(
+                    #[allow(unused_variables)]
+                    #pat => {
+                        #body
+                    }
+                ));
+            }
+
+            if arms.is_empty() {
+                quote!(unsafe { core::unreachable!() })
+            } else {
+                quote!(
+                    match self {
+                        #(#arms),*
+                    }
+                )
+            }
+        }
+
+        Data::Union(..) => {
+            return Err(parse::Error::new(
+                Span::call_site(),
+                "this trait cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote!(
+        impl #impl_generics efmt::uDisplay for #ident #ty_generics #where_clause {
+            fn fmt<W>(&self, #formatter: &mut efmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+            where
+                W: efmt::uWrite + ?Sized,
+            {
+                #body
+            }
+        }
+    ))
+}
+
+/// Writes the format string of the attribute, a unit struct or variant without attribute writes
+/// its name
+fn body(
+    formatter: &Ident,
+    format: Option<Format>,
+    ident: &Ident,
+    fields: &Fields,
+    exprs: &[(String, TokenStream2)],
+) -> parse::Result<TokenStream2> {
+    match format {
+        Some(format) => format_gen(quote!(#formatter), &format, false, exprs),
+        None if matches!(fields, Fields::Unit) => {
+            let ident_s = ident.to_string();
+            Ok(quote!(#formatter.write_str(#ident_s)))
+        }
+        None => Err(parse::Error::new(
+            ident.span(),
+            "missing `#[udisplay(\"...\")]` attribute",
+        )),
+    }
+}
+
+/// Parses `#[udisplay("format string", args..)]`
+fn format_attr(attrs: &[Attribute]) -> parse::Result<Option<Format>> {
+    let mut format = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("udisplay")) {
+        if format.is_some() {
+            return Err(parse::Error::new(
+                attr.span(),
+                "duplicate `#[udisplay(..)]` attribute",
+            ));
+        }
+        format = Some(attr.parse_args::<Format>()?);
+    }
+    Ok(format)
+}
@@ -11,6 +11,9 @@ use write_gen::write;
 mod debug_gen;
 use debug_gen::debug_gen;
 
+mod display_gen;
+use display_gen::display_gen;
+
 use proc_macro::TokenStream;
 
 /// Parse format string and generate code
@@ -33,7 +36,33 @@ pub fn uwriteln(input: TokenStream) -> TokenStream {
 /// - `enum`-s
 ///
 /// `union`-s are not supported
-#[proc_macro_derive(uDebug)]
+///
+/// The output can be adjusted with `#[udebug(..)]` attributes:
+///
+/// - `#[udebug(bound = "T: efmt::uDebug")]` on the `struct` or `enum` replaces the `uDebug` bounds,
+///   which are added to all type parameters
+/// - `#[udebug(transparent)]` on a `struct` with one field writes only this field
+/// - `#[udebug(rename = "name")]` on a field or variant writes it with another name
+/// - `#[udebug(skip)]` on a field omits it
+/// - `#[udebug(hex)]` on a field writes it as hex number like `{:#x}`
+/// - `#[udebug(redact)]` on a field writes `***` instead of the value, `#[udebug(redact = "<key>")]`
+///   another placeholder, `#[udebug(redact, len)]` adds the length in bytes like `efmt::Redacted`
+/// - `#[udebug(with = path)]` on a field writes it with a function
+///   `fn path<W: uWrite + ?Sized>(&T, &mut Formatter<'_, W>) -> Result<(), W::Error>`
+#[proc_macro_derive(uDebug, attributes(udebug))]
 pub fn debug(input: TokenStream) -> TokenStream {
     debug_gen(input)
 }
+
+/// Automatically derive the `uDisplay` trait for a `struct` or `enum`
+///
+/// The output is given by `#[udisplay("format string", args..)]` on the `struct` or on each
+/// variant of the `enum`, with the syntax of `uwrite!`. Fields are referenced by their name
+/// (`{temp:08.2}`) or, if there are no positional arguments, by their index (`{0:x}`). Unit
+/// variants and unit structs without attribute write their name.
+///
+/// `union`-s are not supported
+#[proc_macro_derive(uDisplay, attributes(udisplay))]
+pub fn display(input: TokenStream) -> TokenStream {
+    display_gen(input)
+}
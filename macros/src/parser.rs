@@ -12,51 +12,144 @@ pub enum Alignment {
     Usual,
 }
 
+/// Selects the argument a format piece refers to
+#[derive(Debug, PartialEq)]
+pub enum Argument<'a> {
+    /// `{}`: the next positional argument
+    Next,
+    /// `{0}`: an explicit positional argument
+    Index(usize),
+    /// `{name}`: a named argument or a captured identifier
+    Name(&'a str),
+}
+
+/// A width or precision, either fixed in the format string or taken from an argument at runtime
+#[derive(Debug, PartialEq)]
+pub enum Count<'a> {
+    /// `8`: a literal count
+    Is(usize),
+    /// `1$`, `name$` or `*` (next argument): a `usize` argument
+    Arg(Argument<'a>),
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Piece<'a> {
-    Debug(bool),
-    Display,
+    Debug {
+        arg: Argument<'a>,
+        pretty: bool,
+        hex: Option<bool>,
+    },
+    Display {
+        arg: Argument<'a>,
+    },
     Str(Cow<'a, str>),
-    Float {
-        pad_length: usize,
+    Precision {
+        arg: Argument<'a>,
+        prefix: bool,
+        pad_length: Count<'a>,
         pad_char: char,
+        sign: bool,
         alignment: Alignment,
-        behind: usize,
+        behind: Count<'a>,
     },
     Formatted {
+        arg: Argument<'a>,
         prefix: bool,
         cmd: char,
-        pad_length: usize,
+        pad_length: Count<'a>,
+        pad_char: char,
+        sign: bool,
+        alignment: Alignment,
+        behind: Count<'a>,
+    },
+    Exp {
+        arg: Argument<'a>,
+        pad_length: Count<'a>,
         pad_char: char,
+        sign: bool,
+        alignment: Alignment,
+        behind: Option<Count<'a>>,
+        upper: bool,
+    },
+    Shortest {
+        arg: Argument<'a>,
+        pad_length: Count<'a>,
+        pad_char: char,
+        sign: bool,
         alignment: Alignment,
-        behind: usize,
     },
     Hex {
+        arg: Argument<'a>,
         prefix: bool,
         cmd: char,
-        pad_length: usize,
+        pad_length: Count<'a>,
         pad_char: char,
+        sign: bool,
         alignment: Alignment,
     },
     Padded {
-        pad_length: usize,
+        arg: Argument<'a>,
+        pad_length: Count<'a>,
         pad_char: char,
+        sign: bool,
         alignment: Alignment,
     },
 }
 
-impl Piece<'_> {
-    pub fn is_str(&self) -> bool {
-        matches!(self, Piece::Str(_))
+impl<'a> Piece<'a> {
+    /// Returns the arguments this piece refers to in the order in which they are consumed: the
+    /// width, the precision and finally the value itself
+    pub fn args(&self) -> Vec<&Argument<'a>> {
+        let (counts, arg) = match self {
+            Piece::Str(_) => return vec![],
+            Piece::Debug { arg, .. } | Piece::Display { arg } => ([None, None], arg),
+            Piece::Precision {
+                arg,
+                pad_length,
+                behind,
+                ..
+            }
+            | Piece::Formatted {
+                arg,
+                pad_length,
+                behind,
+                ..
+            } => ([Some(pad_length), Some(behind)], arg),
+            Piece::Exp {
+                arg,
+                pad_length,
+                behind,
+                ..
+            } => ([Some(pad_length), behind.as_ref()], arg),
+            Piece::Hex {
+                arg, pad_length, ..
+            }
+            | Piece::Shortest {
+                arg, pad_length, ..
+            }
+            | Piece::Padded {
+                arg, pad_length, ..
+            } => ([Some(pad_length), None], arg),
+        };
+
+        let mut args = vec![];
+        for count in counts.into_iter().flatten() {
+            if let Count::Arg(arg) = count {
+                args.push(arg);
+            }
+        }
+        args.push(arg);
+        args
     }
 }
 
+/// Creates the identifier the `i`-th (evaluated) argument is bound to
 pub fn mk_ident(i: usize) -> Ident {
     Ident::new(&format!("__{}", i), Span::call_site())
 }
 
 // `}}` -> `}`
-fn unescape(mut literal: &str, span: Span) -> syn::parse::Result<Cow<str>> {
+fn unescape(mut literal: &str, span: Span) -> syn::parse::Result<Cow<'_, str>> {
     if literal.contains('}') {
         let mut buf = String::new();
 
@@ -92,7 +185,7 @@ fn unescape(mut literal: &str, span: Span) -> syn::parse::Result<Cow<str>> {
 
 const INVALID_FORMAT_STR: &str = "invalid format string";
 
-pub fn parse(mut literal: &str, span: Span) -> syn::parse::Result<Vec<Piece>> {
+pub fn parse(mut literal: &str, span: Span) -> syn::parse::Result<Vec<Piece<'_>>> {
     let mut pieces = vec![];
 
     let mut buf = String::new();
@@ -120,7 +213,13 @@ pub fn parse(mut literal: &str, span: Span) -> syn::parse::Result<Vec<Piece>> {
                 const ESCAPED_BRACE: &str = "{";
 
                 let head = head.unwrap_or("");
-                if tail.starts_with(DISPLAY) || tail.starts_with(':') {
+                if let Some(tail) = tail.strip_prefix(ESCAPED_BRACE) {
+                    buf.push_str(&unescape(head, span)?);
+                    buf.push('{');
+                    literal = tail;
+                } else {
+                    let (arg, tail) = parse_argument(tail, span)?;
+
                     if buf.is_empty() {
                         if !head.is_empty() {
                             pieces.push(Piece::Str(unescape(head, span)?));
@@ -131,19 +230,13 @@ pub fn parse(mut literal: &str, span: Span) -> syn::parse::Result<Vec<Piece>> {
                     }
 
                     if let Some(format) = tail.strip_prefix(':') {
-                        let (piece, remainder) = parse_colon(format, span)?;
+                        let (piece, remainder) = parse_colon(arg, format, span)?;
                         pieces.push(piece);
                         literal = remainder;
                     } else {
-                        pieces.push(Piece::Display);
+                        pieces.push(Piece::Display { arg });
                         literal = &tail[DISPLAY.len()..];
                     }
-                } else if let Some(tail) = tail.strip_prefix(ESCAPED_BRACE) {
-                    buf.push_str(&unescape(head, span)?);
-                    buf.push('{');
-                    literal = tail;
-                } else {
-                    return Err(syn::parse::Error::new(span, INVALID_FORMAT_STR));
                 }
             }
         }
@@ -151,22 +244,86 @@ pub fn parse(mut literal: &str, span: Span) -> syn::parse::Result<Vec<Piece>> {
     Ok(pieces)
 }
 
+/// parses the argument selector after a `{` into an [Argument] and the trailing `&str`, which
+/// starts either with `:` or `}`
+fn parse_argument(tail: &str, span: Span) -> syn::parse::Result<(Argument<'_>, &str)> {
+    let end = tail
+        .find([':', '}'])
+        .ok_or_else(|| syn::parse::Error::new(span, INVALID_FORMAT_STR))?;
+    let (name, tail) = tail.split_at(end);
+
+    let arg = if name.is_empty() {
+        Argument::Next
+    } else if name.bytes().all(|b| b.is_ascii_digit()) {
+        let index = name
+            .parse()
+            .map_err(|_| syn::parse::Error::new(span, INVALID_FORMAT_STR))?;
+        Argument::Index(index)
+    } else if is_identifier(name) {
+        Argument::Name(name)
+    } else {
+        return Err(syn::parse::Error::new(span, INVALID_FORMAT_STR));
+    };
+
+    Ok((arg, tail))
+}
+
+/// Checks whether `s` is usable as a named argument, i.e. is a (non raw) Rust identifier
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c == '_' || c.is_alphabetic() => {
+            s != "_" && chars.all(|c| c == '_' || c.is_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+/// parses a width or precision (`8`, `1$` or `name$`) at the beginning of `s`, returns `None` if
+/// there is none
+fn parse_count(s: &str) -> Option<(Count<'_>, &str)> {
+    let digits = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if digits > 0 {
+        let n = s[..digits].parse().ok()?;
+        return match s[digits..].strip_prefix('$') {
+            Some(tail) => Some((Count::Arg(Argument::Index(n)), tail)),
+            None => Some((Count::Is(n), &s[digits..])),
+        };
+    }
+
+    let end = s.find(|c: char| c != '_' && !c.is_alphanumeric())?;
+    let (name, tail) = s.split_at(end);
+    match tail.strip_prefix('$') {
+        Some(tail) if is_identifier(name) => Some((Count::Arg(Argument::Name(name)), tail)),
+        _ => None,
+    }
+}
+
 /// parses the stuff after a `{:` into a [Piece] and the trailing `&str` (what comes after the `}`)
-fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
+fn parse_colon<'a>(
+    arg: Argument<'a>,
+    format: &'a str,
+    span: Span,
+) -> syn::parse::Result<(Piece<'a>, &'a str)> {
     let err_piece = || -> syn::Error { syn::parse::Error::new(span, INVALID_FORMAT_STR) };
 
     let mut chars = format.chars();
     let ch = chars.next().ok_or(err_piece())?;
 
-    let (ch, pad_char) = match ch { // {:0^17.6}
-        '1'..='9'|'<'|'>'|'^'|'#'|'.'|'?'|'A'..='Z'|'a'..='z' => (ch, b' '),
+    let (ch, pad_char) = match ch {
+        // {:0^17.6}
+        '1'..='9' | '<' | '>' | '^' | '#' | '.' | '?' | 'A'..='Z' | 'a'..='z' => (ch, b' '),
+        // {:0$}, width taken from the first argument
+        '0' if chars.as_str().starts_with('$') => (ch, b' '),
+        // {:+8}, the sign flag, but {:+<8} uses `+` as padding character
+        '+' if !chars.as_str().starts_with(['<', '>', '^']) => (ch, b' '),
         _ => {
             let pad_char = if (ch as u32) < 0x80 {
                 ch as u8
             } else {
-                return Err(err_piece())
+                return Err(err_piece());
             };
-            (chars.next().ok_or(err_piece())?, pad_char)   
+            (chars.next().ok_or(err_piece())?, pad_char)
         }
     };
 
@@ -177,6 +334,13 @@ fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
         _ => (ch, Alignment::Usual),
     };
 
+    let (ch, sign) = if ch == '+' {
+        let ch = chars.next().ok_or(err_piece())?;
+        (ch, true)
+    } else {
+        (ch, false)
+    };
+
     let (ch, prefix) = if ch == '#' {
         let ch = chars.next().ok_or(err_piece())?;
         (ch, true)
@@ -184,58 +348,120 @@ fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
         (ch, false)
     };
 
-    let (mut ch, pad_char) = if ch == '0' {
+    // the `0` flag pads numbers with zeros after the sign, it overrides the alignment like in
+    // `core::fmt`, `{:0>8}` pads with the character `0` instead
+    let (ch, pad_char, alignment) = if ch == '0' && !chars.as_str().starts_with('$') {
         let ch = chars.next().ok_or(err_piece())?;
-        (ch, b'0')
+        (ch, b'0', Alignment::Usual)
     } else {
-        (ch, pad_char)
+        (ch, pad_char, alignment)
     };
 
-    let mut pad_length = 0_usize;
-    while ch.is_ascii_digit() {
-        pad_length = pad_length * 10 + ch.to_digit(10).unwrap() as usize;
-        ch = chars.next().ok_or(err_piece())?;
-    }
+    // the rest of the format, starting with `ch`
+    let rest = &format[format.len() - chars.as_str().len() - ch.len_utf8()..];
+    let (pad_length, rest) = parse_count(rest).unwrap_or((Count::Is(0), rest));
+
+    let mut chars = rest.chars();
+    let ch = chars.next().ok_or(err_piece())?;
+    let cmd = match ch {
+        '.' | '?' | 'A'..='Z' | 'a'..='z' => ch,
+        '}' => '*',
+        _ => return Err(err_piece()),
+    };
 
-    let (mut ch, cmd) = match ch {
-        '.' | '?' | 'A'..='Z' | 'a'..='z' => (chars.next().ok_or(err_piece())?, ch),
-        _ => (ch, '*'),
+    // `{:x?}` and `{:X?}`, debug output with integers in hex, `hex` is set to `Some(upper)`
+    let (cmd, hex) = match cmd {
+        'x' | 'X' if chars.as_str().starts_with('?') => {
+            chars.next();
+            ('?', Some(cmd == 'X'))
+        }
+        _ => (cmd, None),
     };
 
-    let mut behind = 0_usize;
-    while ch.is_ascii_digit() {
-        behind = behind * 10 + ch.to_digit(10).unwrap() as usize;
-        ch = chars.next().ok_or(err_piece())?;
-    }
+    // `exp` is set for `{:e}`, `{:E}`, `{:.3e}` and `{:.3E}`, `behind` is `None` if it is missing
+    let (behind, exp) = if cmd == '*' {
+        (None, None)
+    } else {
+        let rest = chars.as_str();
+        let (behind, rest) = match rest.strip_prefix('*') {
+            Some(rest) if cmd == '.' => (Some(Count::Arg(Argument::Next)), rest),
+            _ => match parse_count(rest) {
+                Some((behind, rest)) => (Some(behind), rest),
+                None => (None, rest),
+            },
+        };
+        let (exp, rest) = match (cmd, behind.is_some(), rest.chars().next()) {
+            ('.', true, Some(c @ ('e' | 'E'))) => (Some(c == 'E'), &rest[1..]),
+            ('e' | 'E', false, _) => (Some(cmd == 'E'), rest),
+            _ => (None, rest),
+        };
+        chars = rest.strip_prefix('}').ok_or(err_piece())?.chars();
+        (behind, exp)
+    };
 
-    if ch != '}' {
-        return Err(err_piece());
+    if let Some(upper) = exp {
+        return if !prefix && !matches!(behind, Some(Count::Is(18..))) {
+            Ok((
+                Piece::Exp {
+                    arg,
+                    pad_length,
+                    pad_char: pad_char as char,
+                    sign,
+                    alignment,
+                    behind,
+                    upper,
+                },
+                chars.as_str(),
+            ))
+        } else {
+            Err(err_piece())
+        };
+    }
+    // `{:r}`, shortest representation of a float, `{:r3}` is passed on to `uDisplayFormatted`
+    if cmd == 'r' && behind.is_none() && !prefix {
+        return Ok((
+            Piece::Shortest {
+                arg,
+                pad_length,
+                pad_char: pad_char as char,
+                sign,
+                alignment,
+            },
+            chars.as_str(),
+        ));
     }
+    let behind = behind.unwrap_or(Count::Is(0));
 
     match cmd {
-        '.' => {
-            if behind < 7 && prefix == false {
-                Ok((
-                    Piece::Float {
-                        pad_length,
-                        pad_char: pad_char as char,
-                        alignment,
-                        behind,
-                    },
-                    chars.as_str(),
-                ))
-            } else {
-                Err(err_piece())
-            }
-        }
+        // decimal places of floats or the maximum number of chars of strings, up to 17 decimal
+        // places are supported by the float conversion, a higher precision given at runtime is
+        // handled by the overflow policy
+        '.' if matches!(behind, Count::Is(18..)) => Err(syn::parse::Error::new(
+            span,
+            "a literal precision above 17 is not supported, use `{:.*}`",
+        )),
+        '.' => Ok((
+            Piece::Precision {
+                arg,
+                prefix,
+                pad_length,
+                pad_char: pad_char as char,
+                sign,
+                alignment,
+                behind,
+            },
+            chars.as_str(),
+        )),
         'x' | 'X' | 'b' | 'o' => {
-            if behind == 0 {
+            if behind == Count::Is(0) {
                 Ok((
                     Piece::Hex {
+                        arg,
                         prefix,
                         cmd,
                         pad_length,
                         pad_char: pad_char as char,
+                        sign,
                         alignment,
                     },
                     chars.as_str(),
@@ -245,18 +471,27 @@ fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
             }
         }
         '?' => {
-            if pad_length == 0 && behind == 0 {
-                Ok((Piece::Debug(prefix), chars.as_str()))
+            if pad_length == Count::Is(0) && behind == Count::Is(0) && !sign {
+                Ok((
+                    Piece::Debug {
+                        arg,
+                        pretty: prefix,
+                        hex,
+                    },
+                    chars.as_str(),
+                ))
             } else {
                 Err(err_piece())
             }
         }
         '*' => {
-            if behind == 0 && prefix == false {
+            if behind == Count::Is(0) && !prefix {
                 Ok((
                     Piece::Padded {
+                        arg,
                         pad_length,
                         pad_char: pad_char as char,
+                        sign,
                         alignment,
                     },
                     chars.as_str(),
@@ -267,10 +502,12 @@ fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
         }
         _ => Ok((
             Piece::Formatted {
+                arg,
                 prefix,
-                cmd: cmd,
-                pad_length: pad_length,
+                cmd,
+                pad_length,
                 pad_char: pad_char as char,
+                sign,
                 alignment,
                 behind,
             },
@@ -281,7 +518,7 @@ fn parse_colon(format: &str, span: Span) -> syn::parse::Result<(Piece, &str)> {
 
 #[cfg(test)]
 mod tests {
-    use super::Alignment;
+    use super::{Alignment, Argument, Count};
     use crate::Piece;
     use proc_macro2::Span;
     use std::borrow::Cow;
@@ -295,95 +532,136 @@ mod tests {
             super::parse("The answer is {}", span).ok(),
             Some(vec![
                 Piece::Str(Cow::Borrowed("The answer is ")),
-                Piece::Display
+                Piece::Display {
+                    arg: Argument::Next
+                }
             ]),
         );
 
         assert_eq!(
             super::parse("{:.0}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 0,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(0),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
-                behind: 0
+                behind: Count::Is(0)
             }]),
         );
 
         assert_eq!(
             super::parse("{:.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 0,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Is(6)
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:.17}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(0),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
-                behind: 6
+                behind: Count::Is(17)
             }]),
         );
 
         assert_eq!(
             super::parse("{:17.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 17,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
-                behind: 6
+                behind: Count::Is(6)
             }]),
         );
 
         assert_eq!(
             super::parse("{:<17.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 17,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Left,
-                behind: 6
+                behind: Count::Is(6)
             }]),
         );
 
         assert_eq!(
             super::parse("{:>17.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 17,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Right,
-                behind: 6
+                behind: Count::Is(6)
             }]),
         );
 
         assert_eq!(
             super::parse("{:^17.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 17,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Center,
-                behind: 6
+                behind: Count::Is(6)
             }]),
         );
 
         assert_eq!(
             super::parse("{:0^17.6}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 17,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(17),
                 pad_char: '0',
+                sign: false,
                 alignment: Alignment::Center,
-                behind: 6
+                behind: Count::Is(6)
             }]),
         );
 
         assert_eq!(
             super::parse("{:0^20.2}", span).ok(),
-            Some(vec![Piece::Float {
-                pad_length: 20,
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(20),
                 pad_char: '0',
+                sign: false,
                 alignment: Alignment::Center,
-                behind: 2
+                behind: Count::Is(2)
             }]),
         );
 
         assert_eq!(
             super::parse("{:<27}", span).ok(),
             Some(vec![Piece::Padded {
-                pad_length: 27,
+                arg: Argument::Next,
+                pad_length: Count::Is(27),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Left,
             }]),
         );
@@ -391,8 +669,10 @@ mod tests {
         assert_eq!(
             super::parse("{:>27}", span).ok(),
             Some(vec![Piece::Padded {
-                pad_length: 27,
+                arg: Argument::Next,
+                pad_length: Count::Is(27),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Right,
             }]),
         );
@@ -400,8 +680,10 @@ mod tests {
         assert_eq!(
             super::parse("{:^27}", span).ok(),
             Some(vec![Piece::Padded {
-                pad_length: 27,
+                arg: Argument::Next,
+                pad_length: Count::Is(27),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Center,
             }]),
         );
@@ -409,8 +691,10 @@ mod tests {
         assert_eq!(
             super::parse("{:27}", span).ok(),
             Some(vec![Piece::Padded {
-                pad_length: 27,
+                arg: Argument::Next,
+                pad_length: Count::Is(27),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
             }]),
         );
@@ -418,8 +702,10 @@ mod tests {
         assert_eq!(
             super::parse("{:0<27}", span).ok(),
             Some(vec![Piece::Padded {
-                pad_length: 27,
+                arg: Argument::Next,
+                pad_length: Count::Is(27),
                 pad_char: '0',
+                sign: false,
                 alignment: Alignment::Left,
             }]),
         );
@@ -427,10 +713,12 @@ mod tests {
         assert_eq!(
             super::parse("{:x}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'x',
-                pad_length: 0,
+                pad_length: Count::Is(0),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
             }]),
         );
@@ -438,10 +726,12 @@ mod tests {
         assert_eq!(
             super::parse("{:17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Usual,
             }]),
         );
@@ -449,10 +739,12 @@ mod tests {
         assert_eq!(
             super::parse("{:<17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Left,
             }]),
         );
@@ -460,10 +752,12 @@ mod tests {
         assert_eq!(
             super::parse("{:>17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Right,
             }]),
         );
@@ -471,10 +765,12 @@ mod tests {
         assert_eq!(
             super::parse("{:^17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: ' ',
+                sign: false,
                 alignment: Alignment::Center,
             }]),
         );
@@ -482,10 +778,12 @@ mod tests {
         assert_eq!(
             super::parse("{:0^17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: false,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: '0',
+                sign: false,
                 alignment: Alignment::Center,
             }]),
         );
@@ -493,24 +791,331 @@ mod tests {
         assert_eq!(
             super::parse("{:0^#17X}", span).ok(),
             Some(vec![Piece::Hex {
+                arg: Argument::Next,
                 prefix: true,
                 cmd: 'X',
-                pad_length: 17,
+                pad_length: Count::Is(17),
                 pad_char: '0',
+                sign: false,
                 alignment: Alignment::Center,
             }]),
         );
 
         assert_eq!(
             super::parse("{:?}", span).ok(),
-            Some(vec![Piece::Debug(false)]),
+            Some(vec![Piece::Debug {
+                arg: Argument::Next,
+                pretty: false,
+                hex: None,
+            }]),
         );
 
         assert_eq!(
             super::parse("{:#?}", span).ok(),
-            Some(vec![Piece::Debug(true)]),
+            Some(vec![Piece::Debug {
+                arg: Argument::Next,
+                pretty: true,
+                hex: None,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:#X?}", span).ok(),
+            Some(vec![Piece::Debug {
+                arg: Argument::Next,
+                pretty: true,
+                hex: Some(true),
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:x?}", span).ok(),
+            Some(vec![Piece::Debug {
+                arg: Argument::Next,
+                pretty: false,
+                hex: Some(false),
+            }]),
+        );
+
+        assert!(super::parse("{:8x?}", span).is_err());
+
+        // positional and named arguments
+        assert_eq!(
+            super::parse("{1} {0} {1}", span).ok(),
+            Some(vec![
+                Piece::Display {
+                    arg: Argument::Index(1)
+                },
+                Piece::Str(Cow::Borrowed(" ")),
+                Piece::Display {
+                    arg: Argument::Index(0)
+                },
+                Piece::Str(Cow::Borrowed(" ")),
+                Piece::Display {
+                    arg: Argument::Index(1)
+                },
+            ]),
+        );
+
+        assert_eq!(
+            super::parse("{temp:08.2}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Name("temp"),
+                prefix: false,
+                pad_length: Count::Is(8),
+                pad_char: '0',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Is(2)
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{_x:#?}", span).ok(),
+            Some(vec![Piece::Debug {
+                arg: Argument::Name("_x"),
+                pretty: true,
+                hex: None,
+            }]),
+        );
+
+        // runtime width and precision
+        assert_eq!(
+            super::parse("{:1$}", span).ok(),
+            Some(vec![Piece::Padded {
+                arg: Argument::Next,
+                pad_length: Count::Arg(Argument::Index(1)),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:.*}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Arg(Argument::Next),
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{val:0>width$.prec$}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Name("val"),
+                prefix: false,
+                pad_length: Count::Arg(Argument::Name("width")),
+                pad_char: '0',
+                sign: false,
+                alignment: Alignment::Right,
+                behind: Count::Arg(Argument::Name("prec")),
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:#w$x}", span).ok(),
+            Some(vec![Piece::Hex {
+                arg: Argument::Next,
+                prefix: true,
+                cmd: 'x',
+                pad_length: Count::Arg(Argument::Name("w")),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{2:<0$.1$}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Index(2),
+                prefix: false,
+                pad_length: Count::Arg(Argument::Index(0)),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Left,
+                behind: Count::Arg(Argument::Index(1)),
+            }]),
+        );
+
+        // exponential representation
+        assert_eq!(
+            super::parse("{:e}", span).ok(),
+            Some(vec![Piece::Exp {
+                arg: Argument::Next,
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: None,
+                upper: false,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:<12.3E}", span).ok(),
+            Some(vec![Piece::Exp {
+                arg: Argument::Next,
+                pad_length: Count::Is(12),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Left,
+                behind: Some(Count::Is(3)),
+                upper: true,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:.*e}", span).ok(),
+            Some(vec![Piece::Exp {
+                arg: Argument::Next,
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Some(Count::Arg(Argument::Next)),
+                upper: false,
+            }]),
+        );
+
+        // `e` and `E` followed by a count remain user defined commands
+        assert_eq!(
+            super::parse("{:014E6}", span).ok(),
+            Some(vec![Piece::Formatted {
+                arg: Argument::Next,
+                prefix: false,
+                cmd: 'E',
+                pad_length: Count::Is(14),
+                pad_char: '0',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Is(6),
+            }]),
+        );
+
+        // shortest representation
+        assert_eq!(
+            super::parse("{:>12r}", span).ok(),
+            Some(vec![Piece::Shortest {
+                arg: Argument::Next,
+                pad_length: Count::Is(12),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Right,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:r3}", span).ok(),
+            Some(vec![Piece::Formatted {
+                arg: Argument::Next,
+                prefix: false,
+                cmd: 'r',
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Is(3),
+            }]),
+        );
+
+        // sign flag
+        assert_eq!(
+            super::parse("{:+}", span).ok(),
+            Some(vec![Piece::Padded {
+                arg: Argument::Next,
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: true,
+                alignment: Alignment::Usual,
+            }]),
+        );
+
+        // the `0` flag overrides the alignment
+        assert_eq!(
+            super::parse("{:<+08.2}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: false,
+                pad_length: Count::Is(8),
+                pad_char: '0',
+                sign: true,
+                alignment: Alignment::Usual,
+                behind: Count::Is(2),
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:+#x}", span).ok(),
+            Some(vec![Piece::Hex {
+                arg: Argument::Next,
+                prefix: true,
+                cmd: 'x',
+                pad_length: Count::Is(0),
+                pad_char: ' ',
+                sign: true,
+                alignment: Alignment::Usual,
+            }]),
+        );
+
+        assert_eq!(
+            super::parse("{:0>8}", span).ok(),
+            Some(vec![Piece::Padded {
+                arg: Argument::Next,
+                pad_length: Count::Is(8),
+                pad_char: '0',
+                sign: false,
+                alignment: Alignment::Right,
+            }]),
+        );
+
+        // `+` followed by an alignment is the padding character
+        assert_eq!(
+            super::parse("{:+<5}", span).ok(),
+            Some(vec![Piece::Padded {
+                arg: Argument::Next,
+                pad_length: Count::Is(5),
+                pad_char: '+',
+                sign: false,
+                alignment: Alignment::Left,
+            }]),
+        );
+
+        assert!(super::parse("{:+?}", span).is_err());
+
+        assert!(super::parse("{:.18e}", span).is_err());
+        assert!(super::parse("{:.18}", span).is_err());
+        assert!(super::parse("{:8.20}", span).is_err());
+        assert!(super::parse("{:#e}", span).is_err());
+        assert!(super::parse("{:.e}", span).is_err());
+
+        // truncated string with ellipsis
+        assert_eq!(
+            super::parse("{:#8.17}", span).ok(),
+            Some(vec![Piece::Precision {
+                arg: Argument::Next,
+                prefix: true,
+                pad_length: Count::Is(8),
+                pad_char: ' ',
+                sign: false,
+                alignment: Alignment::Usual,
+                behind: Count::Is(17),
+            }]),
         );
 
+        assert!(super::parse("{:x.*}", span).is_err());
+        assert!(super::parse("{:1$?}", span).is_err());
+
+        assert!(super::parse("{_}", span).is_err());
+        assert!(super::parse("{1a}", span).is_err());
+        assert!(super::parse("{a-b}", span).is_err());
+        assert!(super::parse("{a", span).is_err());
+
         // escaped braces
         assert_eq!(
             super::parse("{{}} is not an argument", span).ok(),
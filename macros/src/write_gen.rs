@@ -1,10 +1,9 @@
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use std::cmp::Ordering;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 
 use crate::{
     mk_ident,
-    parser::{self, Alignment},
+    parser::{self, Alignment, Argument, Count},
     Piece,
 };
 
@@ -14,181 +13,455 @@ use syn::{
     parse_macro_input,
     punctuated::Punctuated,
     spanned::Spanned,
-    Expr, LitStr, Token,
+    Expr, Ident, LitStr, Token,
 };
 
 struct Input {
     formatter: Expr,
     _comma: Token![,],
-    literal: LitStr,
-    _comma2: Option<Token![,]>,
-    args: Punctuated<Expr, Token![,]>,
+    format: Format,
 }
 
 impl Parse for Input {
     fn parse(input: ParseStream) -> parse::Result<Self> {
-        let formatter = input.parse()?;
-        let _comma = input.parse()?;
+        Ok(Input {
+            formatter: input.parse()?,
+            _comma: input.parse()?,
+            format: input.parse()?,
+        })
+    }
+}
+
+/// The format string and its arguments, as in `uwrite!` or in `#[udisplay(..)]`
+pub struct Format {
+    literal: LitStr,
+    args: Punctuated<FormatArg, Token![,]>,
+}
+
+impl Format {
+    pub fn span(&self) -> Span {
+        self.literal.span()
+    }
+}
+
+impl Parse for Format {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
         let literal = input.parse()?;
 
         if input.is_empty() {
-            Ok(Input {
-                formatter,
-                _comma,
+            Ok(Format {
                 literal,
-                _comma2: None,
                 args: Punctuated::new(),
             })
         } else {
-            Ok(Input {
-                formatter,
-                _comma,
+            let _: Token![,] = input.parse()?;
+            Ok(Format {
                 literal,
-                _comma2: input.parse()?,
                 args: Punctuated::parse_terminated(input)?,
             })
         }
     }
 }
 
+/// A macro argument, either positional `expr` or named `name = expr`
+struct FormatArg {
+    name: Option<Ident>,
+    expr: Expr,
+}
+
+impl Parse for FormatArg {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(FormatArg {
+                name: Some(name),
+                expr: input.parse()?,
+            })
+        } else {
+            Ok(FormatArg {
+                name: None,
+                expr: input.parse()?,
+            })
+        }
+    }
+}
+
+/// An argument which gets evaluated exactly once and bound to `mk_ident(index)`
+struct Binding {
+    expr: TokenStream2,
+    name: Option<String>,
+    span: Span,
+    used: bool,
+}
+
+/// Maps the arguments referenced by the pieces to the index of their binding. Arguments that are
+/// named in the format string but not supplied to the macro are taken from `fields` or captured
+/// from the scope.
+fn resolve(
+    pieces: &[Piece],
+    format: &Format,
+    fields: &[(String, TokenStream2)],
+    bindings: &mut Vec<Binding>,
+) -> parse::Result<Vec<usize>> {
+    let literal = &format.literal;
+
+    let mut positional = 0;
+    for arg in &format.args {
+        match &arg.name {
+            Some(name) => {
+                let name_s = name.to_string();
+                if bindings.iter().any(|b| b.name.as_ref() == Some(&name_s)) {
+                    return Err(parse::Error::new(
+                        name.span(),
+                        format!("duplicate argument named `{}`", name_s),
+                    ));
+                }
+                let expr = &arg.expr;
+                bindings.push(Binding {
+                    expr: quote!(#expr),
+                    name: Some(name_s),
+                    span: name.span(),
+                    used: false,
+                });
+            }
+            None => {
+                if bindings.len() > positional {
+                    return Err(parse::Error::new(
+                        arg.expr.span(),
+                        "positional arguments cannot follow named arguments",
+                    ));
+                }
+                let expr = &arg.expr;
+                bindings.push(Binding {
+                    expr: quote!(#expr),
+                    name: None,
+                    span: arg.expr.span(),
+                    used: false,
+                });
+                positional += 1;
+            }
+        }
+    }
+
+    let mut next = 0;
+    let mut indices = vec![];
+    for arg in pieces.iter().flat_map(|piece| piece.args()) {
+        let index = match arg {
+            Argument::Next => {
+                next += 1;
+                next - 1
+            }
+            &Argument::Index(i) => {
+                if i >= positional {
+                    // field of a tuple struct, e.g. `{0}`, only without positional arguments, as
+                    // `{1}` would be ambiguous otherwise
+                    let name = i.to_string();
+                    let field = match positional {
+                        0 => field(fields, &name, literal.span(), bindings),
+                        _ => None,
+                    };
+                    if let Some(index) = field {
+                        index
+                    } else {
+                        return Err(parse::Error::new(
+                            literal.span(),
+                            format!(
+                                "invalid reference to positional argument {} ({})",
+                                i,
+                                match positional {
+                                    0 => "no arguments were given".to_string(),
+                                    1 => "there is 1 argument".to_string(),
+                                    n => format!("there are {} arguments", n),
+                                }
+                            ),
+                        ));
+                    }
+                } else {
+                    i
+                }
+            }
+            &Argument::Name(name) => {
+                match bindings
+                    .iter()
+                    .position(|b| b.name.as_deref() == Some(name))
+                {
+                    Some(index) => index,
+                    None => match field(fields, name, literal.span(), bindings) {
+                        Some(index) => index,
+                        None => {
+                            // implicitly captured identifier, e.g. `{temp}`
+                            let ident = Ident::new(name, literal.span());
+                            bindings.push(Binding {
+                                expr: quote!(#ident),
+                                name: Some(name.to_string()),
+                                span: literal.span(),
+                                used: false,
+                            });
+                            bindings.len() - 1
+                        }
+                    },
+                }
+            }
+        };
+
+        if let Some(binding) = bindings.get_mut(index) {
+            binding.used = true;
+        }
+        indices.push(index);
+    }
+
+    if next > positional {
+        return Err(parse::Error::new(
+            literal.span(),
+            format!(
+                "format string requires {} arguments but {} {} supplied",
+                next,
+                positional,
+                if positional == 1 { "was" } else { "were" }
+            ),
+        ));
+    }
+
+    if let Some(binding) = bindings.iter().find(|b| !b.used) {
+        return Err(parse::Error::new(
+            binding.span,
+            if binding.name.is_some() {
+                "named argument never used"
+            } else {
+                "argument never used"
+            },
+        ));
+    }
+
+    Ok(indices)
+}
+
+/// Index of the binding of the field `name`, the binding is added when it is first referenced
+fn field(
+    fields: &[(String, TokenStream2)],
+    name: &str,
+    span: Span,
+    bindings: &mut Vec<Binding>,
+) -> Option<usize> {
+    let (name, expr) = fields.iter().find(|(field, _)| field == name)?;
+    if let Some(index) = bindings.iter().position(|b| b.name.as_ref() == Some(name)) {
+        return Some(index);
+    }
+    bindings.push(Binding {
+        expr: expr.clone(),
+        name: Some(name.clone()),
+        span,
+        used: false,
+    });
+    Some(bindings.len() - 1)
+}
+
 pub fn write(input: TokenStream, newline: bool) -> TokenStream {
     let input = parse_macro_input!(input as Input);
 
     let formatter = &input.formatter;
-    let literal = input.literal;
+    match format_gen(quote!(#formatter), &input.format, newline, &[]) {
+        Err(e) => e.to_compile_error().into(),
+        Ok(ts) => ts.into(),
+    }
+}
+
+/// Generates the code, which writes the format string into `formatter`. `fields` are the names and
+/// expressions of the fields of a derived type, which can be referenced like named arguments.
+pub fn format_gen(
+    formatter: TokenStream2,
+    format: &Format,
+    newline: bool,
+    fields: &[(String, TokenStream2)],
+) -> parse::Result<TokenStream2> {
+    let literal = &format.literal;
 
-    let mut format = literal.value();
+    let mut string = literal.value();
     if newline {
-        format.push('\n');
-    }
-    let pieces = match parser::parse(&format, literal.span()) {
-        Err(e) => return e.to_compile_error().into(),
-        Ok(pieces) => pieces,
-    };
-
-    let required_args = pieces.iter().filter(|piece| !piece.is_str()).count();
-    let supplied_args = input.args.len();
-    match supplied_args.cmp(&required_args) {
-        Ordering::Less => {
-            return parse::Error::new(
-                literal.span(),
-                &format!(
-                    "format string requires {} arguments but {} {} supplied",
-                    required_args,
-                    supplied_args,
-                    if supplied_args == 1 { "was" } else { "were" }
-                ),
-            )
-            .to_compile_error()
-            .into();
-        }
-        Ordering::Greater => {
-            return parse::Error::new(
-                input.args[required_args].span(),
-                "argument never used".to_string(),
-            )
-            .to_compile_error()
-            .into();
-        }
-        Ordering::Equal => {}
+        string.push('\n');
     }
+    let pieces = parser::parse(&string, literal.span())?;
 
-    let mut args = vec![];
-    let mut pats = vec![];
-    let mut exprs = vec![];
-    let mut i = 0;
-    for piece in pieces {
-        if let Piece::Str(s) = piece {
-            exprs.push(quote!(f.write_str(#s)?;))
-        } else {
-            let pat = mk_ident(i);
-            let arg = &input.args[i];
-            i += 1;
+    let mut bindings = vec![];
+    let indices = resolve(&pieces, format, fields, &mut bindings)?;
 
-            args.push(quote!(&(#arg)));
-            pats.push(quote!(#pat));
+    let args = bindings.iter().map(|b| {
+        let expr = &b.expr;
+        quote!(&(#expr))
+    });
+    let pats = (0..bindings.len()).map(mk_ident);
 
-            match piece {
-                Piece::Debug(pretty) => {
-                    exprs.push(if pretty {
-                        quote!(f.pretty(|f| efmt::uDebug::fmt(#pat, f))?;)
-                    } else {
-                        quote!(efmt::uDebug::fmt(#pat, f)?;)
-                    });
+    let mut exprs = vec![];
+    let mut indices = indices.into_iter();
+    let mut next_pat = || mk_ident(indices.next().expect("UNREACHABLE"));
+    for piece in pieces {
+        match piece {
+            Piece::Str(s) => exprs.push(quote!(f.write_str(#s)?;)),
+            Piece::Debug { pretty, hex, .. } => {
+                let pat = next_pat();
+                let mut call = quote!(efmt::uDebug::fmt(#pat, f));
+                if let Some(upper) = hex {
+                    call = quote!(f.debug_hex(#upper, |f| #call));
                 }
-                Piece::Display => {
-                    exprs.push(quote!(efmt::uDisplay::fmt(#pat, f)?;));
+                if pretty {
+                    call = quote!(f.pretty(|f| #call));
                 }
-                Piece::Str(_) => unreachable!(),
-                Piece::Float {
-                    pad_length,
-                    pad_char,
-                    alignment,
-                    behind,
-                } => {
-                    let alignment = get_alignment(alignment, pad_length);
-                    exprs.push(quote!(efmt::uDisplayFloat::fmt_float(
-                        #pat, 
+                exprs.push(quote!(#call?;));
+            }
+            Piece::Display { .. } => {
+                let pat = next_pat();
+                exprs.push(quote!(efmt::uDisplay::fmt(#pat, f)?;));
+            }
+            Piece::Precision {
+                arg: _,
+                prefix,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+                behind,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let behind = get_count(behind, &mut next_pat);
+                let pat = next_pat();
+                if prefix {
+                    // floats reject the ellipsis flag `#` at compile time
+                    exprs.push(quote!(efmt::unstable_check_prefix(#pat);));
+                }
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayPrecision::fmt_precision(
+                        #pat,
                         f,
+                        #prefix,
                         #alignment,
                         #pad_char,
                         #behind,
-                    )?;));
-                }
-                Piece::Formatted {
-                    prefix,
-                    cmd,
-                    pad_length,
-                    pad_char,
-                    alignment,
-                    behind,
-                } => {
-                    let alignment = get_alignment(alignment, pad_length);
-                    exprs.push(quote!(efmt::uDisplayFormatted::fmt_formatted(
-                        #pat, 
+                    )),
+                ));
+            }
+            Piece::Formatted {
+                arg: _,
+                prefix,
+                cmd,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+                behind,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let behind = get_count(behind, &mut next_pat);
+                let pat = next_pat();
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayFormatted::fmt_formatted(
+                        #pat,
                         f,
                         #prefix,
                         #cmd,
                         #alignment,
                         #pad_char,
                         #behind,
-                    )?;));
-                }
-                Piece::Hex {
-                    prefix,
-                    cmd,
-                    pad_length,
-                    pad_char,
-                    alignment,
-                } => {
-                    let alignment = get_alignment(alignment, pad_length);
-                    exprs.push(quote!(efmt::uDisplayHex::fmt_hex(
-                        #pat, 
+                    )),
+                ));
+            }
+            Piece::Exp {
+                arg: _,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+                behind,
+                upper,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let behind = match behind {
+                    Some(behind) => {
+                        let behind = get_count(behind, &mut next_pat);
+                        quote!(core::option::Option::Some(#behind))
+                    }
+                    None => quote!(core::option::Option::None),
+                };
+                let pat = next_pat();
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayFloat::fmt_exp(
+                        #pat,
+                        f,
+                        #alignment,
+                        #pad_char,
+                        #behind,
+                        #upper,
+                    )),
+                ));
+            }
+            Piece::Shortest {
+                arg: _,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let pat = next_pat();
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayFloat::fmt_shortest(
+                        #pat,
+                        f,
+                        #alignment,
+                        #pad_char,
+                    )),
+                ));
+            }
+            Piece::Hex {
+                arg: _,
+                prefix,
+                cmd,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let pat = next_pat();
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayHex::fmt_hex(
+                        #pat,
                         f,
                         #prefix,
                         #cmd,
                         #alignment,
                         #pad_char,
-                    )?;));
-                }
-                Piece::Padded {
-                    pad_length,
-                    pad_char,
-                    alignment,
-                } => {
-                    let alignment = get_alignment(alignment, pad_length);
-                    exprs.push(quote!(efmt::uDisplayPadded::fmt_padded(
-                        #pat, 
+                    )),
+                ));
+            }
+            Piece::Padded {
+                arg: _,
+                pad_length,
+                pad_char,
+                sign,
+                alignment,
+            } => {
+                let alignment = get_alignment(alignment, get_count(pad_length, &mut next_pat));
+                let pat = next_pat();
+                exprs.push(with_sign(
+                    sign,
+                    quote!(efmt::uDisplayPadded::fmt_padded(
+                        #pat,
                         f,
                         #alignment,
                         #pad_char,
-                    )?;))
-                }
+                    )),
+                ));
             }
         }
     }
 
-    quote!(match (#(#args),*) {
+    Ok(quote!(match (#(#args),*) {
         (#(#pats),*) => {
             use efmt::UnstableDoAsFormatter as _;
 
@@ -197,11 +470,30 @@ pub fn write(input: TokenStream, newline: bool) -> TokenStream {
                 core::result::Result::Ok(())
             })
         }
-    })
-    .into()
+    }))
+}
+
+/// A fixed count is inlined, a runtime count is read from the binding of its `usize` argument
+fn get_count(count: Count, next_pat: &mut impl FnMut() -> Ident) -> TokenStream2 {
+    match count {
+        Count::Is(n) => quote!(#n),
+        Count::Arg(_) => {
+            let pat = next_pat();
+            quote!(*#pat)
+        }
+    }
+}
+
+/// Calls with the sign flag `+` are executed with a formatter, which writes the sign
+fn with_sign(sign: bool, call: TokenStream2) -> TokenStream2 {
+    if sign {
+        quote!(f.sign_plus(|f| #call)?;)
+    } else {
+        quote!(#call?;)
+    }
 }
 
-fn get_alignment(alignment: Alignment, pad_length: usize) -> TokenStream2 {
+fn get_alignment(alignment: Alignment, pad_length: TokenStream2) -> TokenStream2 {
     match alignment {
         Alignment::Left => quote!(efmt::Padding::LeftAligned(#pad_length)),
         Alignment::Right => quote!(efmt::Padding::RightAligned(#pad_length)),
@@ -0,0 +1,343 @@
+//! Exact decimal digit generation for floating point numbers
+//!
+//! A finite float is `mant * 2^exp`. To get its decimal digits without loss of precision, the
+//! value is represented as the fraction `r / s * 10^k` of two big integers with `r / s` in the range
+//! `[0.1, 1)`. Each further digit is then obtained by multiplying `r` by ten and dividing by `s`.
+//! The algorithm is the "fixed precision" variant of Steele & White's Dragon4.
+//!
+//! The shortest representation, which still reads back to the same float, is generated with the
+//! "free format" variant of the algorithm as described by Burger & Dybvig.
+//!
+//! All operations work on fixed size arrays of `N` 32 bit words and are free of panicking
+//! branches. For f32, `N = 8` is sufficient, f64 requires `N = 40`.
+
+use core::cmp::Ordering;
+
+/// Number of words required for the digit generation of f32
+pub(crate) const F32_WORDS: usize = 8;
+
+/// Number of words required for the digit generation of f64
+pub(crate) const F64_WORDS: usize = 40;
+
+/// Unsigned big integer with `N` little endian 32 bit words
+#[derive(Clone)]
+pub(crate) struct Big<const N: usize> {
+    words: [u32; N],
+}
+
+impl<const N: usize> Big<N> {
+    pub(crate) fn from_u64(v: u64) -> Self {
+        let mut words = [0; N];
+        let mut v = v;
+        for w in words.iter_mut() {
+            *w = v as u32;
+            v >>= 32;
+        }
+        Big { words }
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    /// Multiplies with a small number, an overflow of the top word is discarded
+    pub(crate) fn mul_small(&mut self, m: u32) {
+        let mut carry = 0_u64;
+        for w in self.words.iter_mut() {
+            let v = (*w as u64) * (m as u64) + carry;
+            *w = v as u32;
+            carry = v >> 32;
+        }
+    }
+
+    /// Multiplies with `2^bits`
+    pub(crate) fn mul_pow2(&mut self, bits: usize) {
+        let shift = bits / 32;
+        if shift > 0 {
+            let src = self.words;
+            for (i, w) in self.words.iter_mut().enumerate() {
+                *w = match i.checked_sub(shift) {
+                    Some(j) => src.get(j).copied().unwrap_or(0),
+                    None => 0,
+                };
+            }
+        }
+        self.mul_small(1 << (bits % 32));
+    }
+
+    /// Multiplies with `10^n`
+    pub(crate) fn mul_pow10(&mut self, mut n: usize) {
+        const POW10: [u32; 10] = [
+            1,
+            10,
+            100,
+            1_000,
+            10_000,
+            100_000,
+            1_000_000,
+            10_000_000,
+            100_000_000,
+            1_000_000_000,
+        ];
+        while n >= 9 {
+            self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(POW10.get(n).copied().unwrap_or(1));
+    }
+
+    /// Adds `other`, an overflow of the top word is discarded
+    pub(crate) fn add(&mut self, other: &Self) {
+        let mut carry = false;
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            let (v, c1) = a.overflowing_add(*b);
+            let (v, c2) = v.overflowing_add(carry as u32);
+            *a = v;
+            carry = c1 || c2;
+        }
+    }
+
+    /// Subtracts `other`, which must not be greater than `self`
+    pub(crate) fn sub(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            let (v, b1) = a.overflowing_sub(*b);
+            let (v, b2) = v.overflowing_sub(borrow as u32);
+            *a = v;
+            borrow = b1 || b2;
+        }
+    }
+
+    pub(crate) fn cmp(&self, other: &Self) -> Ordering {
+        for (a, b) in self.words.iter().rev().zip(other.words.iter().rev()) {
+            if a != b {
+                return a.cmp(b);
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+/// Splits a f32 into mantissa and binary exponent, the sign is ignored
+pub(crate) fn decode_f32(f: f32) -> (u64, i32) {
+    let bits = f.to_bits();
+    let frac = (bits & 0x7f_ffff) as u64;
+    match ((bits >> 23) & 0xff) as i32 {
+        0 => (frac, -149),
+        e => (frac | 0x80_0000, e - 150),
+    }
+}
+
+/// Splits a f64 into mantissa and binary exponent, the sign is ignored
+pub(crate) fn decode_f64(f: f64) -> (u64, i32) {
+    let bits = f.to_bits();
+    let frac = bits & 0xf_ffff_ffff_ffff;
+    match ((bits >> 52) & 0x7ff) as i32 {
+        0 => (frac, -1074),
+        e => (frac | 0x10_0000_0000_0000, e - 1075),
+    }
+}
+
+/// Generator for the decimal digits of `mant * 2^exp`
+pub(crate) struct Decimal<const N: usize> {
+    r: Big<N>,
+    s: Big<N>,
+    k: i32,
+}
+
+impl<const N: usize> Decimal<N> {
+    /// Prepares the digit generation, `mant` must not be zero
+    pub(crate) fn new(mant: u64, exp: i32) -> Self {
+        let mut r = Big::from_u64(mant);
+        let mut s = Big::from_u64(1);
+        if exp >= 0 {
+            r.mul_pow2(exp as usize);
+        } else {
+            s.mul_pow2(exp.unsigned_abs() as usize);
+        }
+
+        // Estimate k = floor(log10(v)) + 1 from the position of the highest bit. 1233 / 4096 is
+        // close enough to log10(2), the estimate is corrected below.
+        let high_bit = exp + 63 - mant.leading_zeros() as i32;
+        let mut k = ((high_bit * 1233) >> 12) + 1;
+        if k >= 0 {
+            s.mul_pow10(k as usize);
+        } else {
+            r.mul_pow10(k.unsigned_abs() as usize);
+        }
+
+        if !r.is_zero() {
+            while r.cmp(&s) != Ordering::Less {
+                s.mul_small(10);
+                k += 1;
+            }
+            loop {
+                let mut r10 = r.clone();
+                r10.mul_small(10);
+                if r10.cmp(&s) != Ordering::Less {
+                    break;
+                }
+                r = r10;
+                k -= 1;
+            }
+        }
+
+        Decimal { r, s, k }
+    }
+
+    /// Decimal exponent `k` of the value, with `10^(k-1) <= v < 10^k`
+    pub(crate) fn exp(&self) -> i32 {
+        self.k
+    }
+
+    /// Raises the decimal exponent of the first digit to `k`, which produces leading zeros
+    pub(crate) fn set_exp(&mut self, k: i32) {
+        if k > self.k {
+            self.s.mul_pow10((k - self.k) as usize);
+            self.k = k;
+        }
+    }
+
+    /// Fills `out` with ASCII digits, starting at position `10^(k-1)`. The last digit is rounded
+    /// half to even. Returns `true` if the rounding overflowed, in this case all digits are `0`
+    /// and a leading `1` is missing.
+    pub(crate) fn write_digits(&mut self, out: &mut [u8]) -> bool {
+        let mut last = 0;
+        for d in out.iter_mut() {
+            self.r.mul_small(10);
+            last = 0;
+            while last < 9 && self.r.cmp(&self.s) != Ordering::Less {
+                self.r.sub(&self.s);
+                last += 1;
+            }
+            *d = b'0' + last;
+        }
+
+        let mut r2 = self.r.clone();
+        r2.mul_small(2);
+        let round_up = match r2.cmp(&self.s) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => last % 2 == 1,
+        };
+
+        if round_up {
+            for d in out.iter_mut().rev() {
+                if *d == b'9' {
+                    *d = b'0';
+                } else {
+                    *d += 1;
+                    return false;
+                }
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Generates the shortest digits of `mant * 2^exp`, which uniquely identify the float, into `out`
+/// and returns their number and the decimal exponent `k` with `v = 0.d1d2...dn * 10^k`.
+///
+/// `mant` must not be zero, `lower_closer` is set if the next smaller float is closer than the
+/// next larger one (`mant` is a power of two). With an even `mant`, the halfway points between the
+/// float and its neighbours round to the float and are therefore valid representations.
+pub(crate) fn shortest<const N: usize>(
+    mant: u64,
+    exp: i32,
+    lower_closer: bool,
+    out: &mut [u8],
+) -> (usize, i32) {
+    // the float is the interval (r - m_minus, r + m_plus) scaled by 2^exp
+    let (r, m_minus, m_plus, exp) = match lower_closer {
+        true => (mant << 2, 1, 2, exp - 2),
+        false => (mant << 1, 1, 1, exp - 1),
+    };
+    let inclusive = mant & 1 == 0;
+
+    let mut r = Big::<N>::from_u64(r);
+    let mut m_minus = Big::<N>::from_u64(m_minus);
+    let mut m_plus = Big::<N>::from_u64(m_plus);
+    let mut s = Big::<N>::from_u64(1);
+    if exp >= 0 {
+        r.mul_pow2(exp as usize);
+        m_minus.mul_pow2(exp as usize);
+        m_plus.mul_pow2(exp as usize);
+    } else {
+        s.mul_pow2(exp.unsigned_abs() as usize);
+    }
+
+    // `high` compared with `s`, respecting the inclusive upper boundary
+    let high_reaches = |r: &Big<N>, m_plus: &Big<N>, s: &Big<N>| {
+        let mut high = r.clone();
+        high.add(m_plus);
+        match high.cmp(s) {
+            Ordering::Greater => true,
+            Ordering::Equal => inclusive,
+            Ordering::Less => false,
+        }
+    };
+
+    // Estimate k from the upper boundary like in `Decimal::new` and correct it, so that the upper
+    // boundary is below 10^k
+    let high = (mant << 2) + 2;
+    let high_bit = exp + 63 - high.leading_zeros() as i32;
+    let mut k = ((high_bit * 1233) >> 12) + 1;
+    if k >= 0 {
+        s.mul_pow10(k as usize);
+    } else {
+        r.mul_pow10(k.unsigned_abs() as usize);
+        m_minus.mul_pow10(k.unsigned_abs() as usize);
+        m_plus.mul_pow10(k.unsigned_abs() as usize);
+    }
+    while high_reaches(&r, &m_plus, &s) {
+        s.mul_small(10);
+        k += 1;
+    }
+    loop {
+        let mut r10 = r.clone();
+        r10.mul_small(10);
+        let mut m_plus10 = m_plus.clone();
+        m_plus10.mul_small(10);
+        if high_reaches(&r10, &m_plus10, &s) {
+            break;
+        }
+        r = r10;
+        m_plus = m_plus10;
+        m_minus.mul_small(10);
+        k -= 1;
+    }
+
+    let mut n = 0;
+    for d in out.iter_mut() {
+        r.mul_small(10);
+        m_minus.mul_small(10);
+        m_plus.mul_small(10);
+        let mut digit = 0;
+        while digit < 9 && r.cmp(&s) != Ordering::Less {
+            r.sub(&s);
+            digit += 1;
+        }
+        n += 1;
+
+        let low_ok = match r.cmp(&m_minus) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive,
+            Ordering::Greater => false,
+        };
+        let high_ok = high_reaches(&r, &m_plus, &s);
+        *d = b'0' + digit;
+        if low_ok || high_ok {
+            let mut r2 = r.clone();
+            r2.mul_small(2);
+            if !low_ok || (high_ok && r2.cmp(&s) != Ordering::Less) {
+                // round up, the digit is never 9 here: otherwise the upper boundary would have
+                // been reached with the previous digit already
+                *d += 1;
+            }
+            break;
+        }
+    }
+    (n, k)
+}
@@ -141,7 +141,9 @@ where
     pub fn finish(&mut self) -> Result<(), W::Error> {
         if self.formatter.pretty {
             self.formatter.indentation -= 1;
-            self.formatter.indent()?;
+            if !self.first {
+                self.formatter.indent()?;
+            }
         }
 
         self.formatter.write_str("]")
@@ -207,6 +209,13 @@ where
 
     /// Finishes output
     pub fn finish(&mut self) -> Result<(), W::Error> {
+        if self.formatter.pretty {
+            self.formatter.indentation -= 1;
+            if !self.first {
+                self.formatter.indent()?;
+            }
+        }
+
         self.formatter.write_str("}")
     }
 }
@@ -268,6 +277,13 @@ where
 
     /// Finishes output
     pub fn finish(&mut self) -> Result<(), W::Error> {
+        if self.formatter.pretty {
+            self.formatter.indentation -= 1;
+            if !self.first {
+                self.formatter.indent()?;
+            }
+        }
+
         self.formatter.write_str("}")
     }
 }
@@ -292,6 +308,15 @@ where
 {
     /// Adds a new field to the generated struct output.
     pub fn field(&mut self, name: &str, value: &impl uDebug) -> Result<&mut Self, W::Error> {
+        self.field_with(name, |f| value.fmt(f))
+    }
+
+    /// Adds a new field to the generated struct output, the value is written by `fmt`.
+    pub fn field_with(
+        &mut self,
+        name: &str,
+        fmt: impl FnOnce(&mut Formatter<'_, W>) -> Result<(), W::Error>,
+    ) -> Result<&mut Self, W::Error> {
         if self.first {
             self.first = false;
 
@@ -312,7 +337,7 @@ where
 
         self.formatter.write_str(name)?;
         self.formatter.write_str(": ")?;
-        value.fmt(self.formatter)?;
+        fmt(self.formatter)?;
 
         if self.formatter.pretty {
             self.formatter.write_str(",\n")?;
@@ -363,6 +388,14 @@ where
 {
     /// Adds a new field to the generated tuple struct output.
     pub fn field(&mut self, value: &impl uDebug) -> Result<&mut Self, W::Error> {
+        self.field_with(|f| value.fmt(f))
+    }
+
+    /// Adds a new field to the generated tuple struct output, the value is written by `fmt`.
+    pub fn field_with(
+        &mut self,
+        fmt: impl FnOnce(&mut Formatter<'_, W>) -> Result<(), W::Error>,
+    ) -> Result<&mut Self, W::Error> {
         self.fields += 1;
 
         if self.first {
@@ -381,7 +414,7 @@ where
             self.formatter.indent()?;
         }
 
-        value.fmt(self.formatter)?;
+        fmt(self.formatter)?;
 
         if self.formatter.pretty {
             self.formatter.write_str(",\n")?;
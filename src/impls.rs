@@ -1,5 +1,19 @@
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod alloc;
 mod array;
+mod bytes;
 mod core;
 mod float;
+#[cfg(any(feature = "heapless07", feature = "heapless08", feature = "heapless09"))]
+mod heapless;
 mod int;
+mod join;
+mod net;
+mod redacted;
 mod tuple;
+mod types;
+
+pub use bytes::HexBytes;
+pub use join::{join, Separated};
+pub use redacted::Redacted;
+pub use tuple::Joined;
@@ -0,0 +1,164 @@
+use alloc::{borrow::Cow, borrow::ToOwned, boxed::Box, rc::Rc, string::String, vec::Vec};
+
+use crate::{uDebug, uDisplay, uDisplayPadded, uDisplayPrecision, uWrite, Formatter, Padding};
+
+impl<T> uDebug for Vec<T>
+where
+    T: uDebug,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <[T] as uDebug>::fmt(self, f)
+    }
+}
+
+impl uDisplay for String {
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self)
+    }
+}
+
+impl uDebug for String {
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <str as uDebug>::fmt(self, f)
+    }
+}
+
+impl uDisplayPadded for String {
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.as_str().fmt_padded(fmt, padding, pad_char)
+    }
+}
+
+impl uDisplayPrecision for String {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.as_str()
+            .fmt_precision(fmt, prefix, padding, pad_char, behind)
+    }
+}
+
+impl<B> uDebug for Cow<'_, B>
+where
+    B: uDebug + ToOwned + ?Sized,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <B as uDebug>::fmt(self, f)
+    }
+}
+
+impl<B> uDisplay for Cow<'_, B>
+where
+    B: uDisplay + ToOwned + ?Sized,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <B as uDisplay>::fmt(self, f)
+    }
+}
+
+impl uDisplayPadded for Cow<'_, str> {
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        (&**self).fmt_padded(fmt, padding, pad_char)
+    }
+}
+
+impl uDisplayPrecision for Cow<'_, str> {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        (&**self).fmt_precision(fmt, prefix, padding, pad_char, behind)
+    }
+}
+
+// Smart pointers are formatted like the value they point to
+macro_rules! pointer {
+    ($($ptr:ident),+) => {
+        $(
+            impl<T> uDebug for $ptr<T>
+            where
+                T: uDebug + ?Sized,
+            {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDebug>::fmt(self, f)
+                }
+            }
+
+            impl<T> uDisplay for $ptr<T>
+            where
+                T: uDisplay + ?Sized,
+            {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    <T as uDisplay>::fmt(self, f)
+                }
+            }
+        )+
+    };
+}
+
+pointer!(Box, Rc);
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(target_has_atomic = "ptr")]
+pointer!(Arc);
@@ -0,0 +1,256 @@
+use core::str::from_utf8_unchecked;
+
+use super::int::fmt_debug;
+use crate::{uDebug, uDisplayHex, uWrite, width, Formatter, Padding};
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Byte slice, which is written in hex with a separator between the bytes, e.g. `00:1b:63` with
+/// `{:x}`
+///
+/// ```
+/// use efmt::{uformat, HexBytes};
+///
+/// let mac = [0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6];
+/// let s = uformat!(100, "{:x}", HexBytes::new(&mac).separator(":")).unwrap();
+/// assert_eq!("00:1b:63:84:45:e6", s.as_str());
+/// let s = uformat!(100, "{:X}", HexBytes::new(&mac[..3]).separator(" ")).unwrap();
+/// assert_eq!("00 1B 63", s.as_str());
+/// ```
+#[derive(Clone, Copy)]
+pub struct HexBytes<'a> {
+    bytes: &'a [u8],
+    separator: &'a str,
+}
+
+impl<'a> HexBytes<'a> {
+    /// Creates the hex representation of the bytes without separator
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            separator: "",
+        }
+    }
+
+    /// Sets the separator, which is written between the bytes
+    pub fn separator(self, separator: &'a str) -> Self {
+        Self { separator, ..self }
+    }
+}
+
+impl uDisplayHex for HexBytes<'_> {
+    fn fmt_hex<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        cmd: char,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        fmt_bytes(
+            self.bytes,
+            self.separator,
+            fmt,
+            prefix,
+            cmd,
+            padding,
+            pad_char,
+        )
+    }
+}
+
+impl uDebug for HexBytes<'_> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        u8::fmt_slice(self.bytes, f)
+    }
+}
+
+impl uDisplayHex for [u8] {
+    fn fmt_hex<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        cmd: char,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        fmt_bytes(self, "", fmt, prefix, cmd, padding, pad_char)
+    }
+}
+
+// Byte buffers of any size are written as one contiguous number, e.g. `01ab00ff`
+macro_rules! bytes_hex {
+    ($([$($generics:tt)*] $type:ty $(, #[$attr:meta])?);+) => {
+        $(
+            $(#[$attr])?
+            impl<$($generics)*> uDisplayHex for $type {
+                #[inline(always)]
+                fn fmt_hex<W>(
+                    &self,
+                    fmt: &mut Formatter<'_, W>,
+                    prefix: bool,
+                    cmd: char,
+                    padding: Padding,
+                    pad_char: char,
+                ) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    fmt_bytes(self, "", fmt, prefix, cmd, padding, pad_char)
+                }
+            }
+        )+
+    };
+}
+
+bytes_hex!(
+    [const N: usize] [u8; N];
+    [const N: usize] heapless07::Vec<u8, N>, #[cfg(feature = "heapless07")];
+    [const N: usize] heapless08::Vec<u8, N>, #[cfg(feature = "heapless08")];
+    [const N: usize, L: heapless09::LenType] heapless09::Vec<u8, N, L>, #[cfg(feature = "heapless09")]
+);
+
+impl uDebug for u8 {
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        fmt_debug(self, f)
+    }
+
+    fn fmt_slice<W>(slice: &[Self], f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // nested in other values, the bytes are a list like in `core::fmt`, as the lines of the
+        // hex dump would break the indentation. Without bytes there is no hex dump, but `[]`.
+        match f.debug_hex {
+            Some(upper) if f.pretty && f.indentation == 0 && !slice.is_empty() => {
+                hexdump(slice, f, upper)
+            }
+            _ => f.debug_list()?.entries(slice)?.finish(),
+        }
+    }
+}
+
+/// Writes the bytes as one number with a fixed number of digits per byte, e.g. `[0x01, 0xab]` as
+/// `01ab`, the separator is written between the bytes. Nothing is buffered, so the bytes may be
+/// of any length.
+fn fmt_bytes<W>(
+    bytes: &[u8],
+    separator: &str,
+    fmt: &mut Formatter<'_, W>,
+    prefix: bool,
+    cmd: char,
+    padding: Padding,
+    pad_char: char,
+) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let (pre, bits) = match cmd {
+        'o' => ("0o", 3),
+        'b' => ("0b", 1),
+        _ => ("0x", 4), // 'x', 'X'
+    };
+    let pre = if prefix { pre } else { "" };
+    let digits = 8_usize.div_ceil(bits);
+    let table = if cmd == 'X' { UPPER } else { LOWER };
+
+    // With the `0` flag the zeros are placed between the prefix and the digits
+    let zero_flag = pad_char == '0' && matches!(padding, Padding::Usual(_));
+    if zero_flag {
+        fmt.write_str(pre)?;
+    }
+    let len = pre.len() + bytes.len() * digits + bytes.len().saturating_sub(1) * width(separator);
+    fmt.write_padded_with(len, pad_char, padding, |f| {
+        if !zero_flag {
+            f.write_str(pre)?;
+        }
+        for (i, &byte) in bytes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            write_byte(f, byte, bits, digits, table)?;
+        }
+        Ok(())
+    })
+}
+
+/// Writes a byte with `digits` digits of `bits` bits each
+fn write_byte<W>(
+    f: &mut Formatter<'_, W>,
+    byte: u8,
+    bits: usize,
+    digits: usize,
+    table: &[u8; 16],
+) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let mut buf = [0_u8; 8];
+    for (i, d) in buf.iter_mut().take(digits).enumerate() {
+        let shift = bits * (digits - 1 - i);
+        *d = table[(byte >> shift) as usize & ((1 << bits) - 1) & 0xf];
+    }
+    // Safety: buf only contains ASCII digits from the table
+    f.write_str(unsafe { from_utf8_unchecked(buf.get(..digits).unwrap_or_default()) })
+}
+
+/// Writes the bytes like `hexdump -C`: offset, 16 bytes in hex and as ASCII per line, followed
+/// by a line with the length
+fn hexdump<W>(bytes: &[u8], f: &mut Formatter<'_, W>, upper: bool) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let table = if upper { UPPER } else { LOWER };
+    let cmd = if upper { 'X' } else { 'x' };
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        if line > 0 {
+            f.write_str("\n")?;
+            f.indent()?;
+        }
+        (line * 16).fmt_hex(f, false, cmd, Padding::Usual(8), '0')?;
+        f.write_str("  ")?;
+        for i in 0..16 {
+            if i == 8 {
+                f.write_str(" ")?;
+            }
+            match chunk.get(i) {
+                Some(&byte) => {
+                    write_byte(f, byte, 4, 2, table)?;
+                    f.write_str(" ")?;
+                }
+                None => f.write_str("   ")?,
+            }
+        }
+
+        let mut ascii = [b'.'; 16];
+        for (a, &byte) in ascii.iter_mut().zip(chunk) {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                *a = byte;
+            }
+        }
+        f.write_str(" |")?;
+        // Safety: ascii only contains printable ASCII characters
+        f.write_str(unsafe { from_utf8_unchecked(ascii.get(..chunk.len()).unwrap_or_default()) })?;
+        f.write_str("|")?;
+    }
+    if !bytes.is_empty() {
+        f.write_str("\n")?;
+        f.indent()?;
+        bytes.len().fmt_hex(f, false, cmd, Padding::Usual(8), '0')?;
+    }
+    Ok(())
+}
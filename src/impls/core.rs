@@ -1,4 +1,7 @@
-use crate::{uDebug, uDisplay, uDisplayPadded, uWrite, udisplay_as_udebug, Formatter, Padding};
+use crate::{
+    uDebug, uDisplay, uDisplayHex, uDisplayPadded, uDisplayPrecision, uWrite, udisplay_as_udebug,
+    Formatter, Padding,
+};
 
 impl uDisplay for bool {
     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
@@ -40,7 +43,20 @@ impl uDisplay for char {
     }
 }
 
-udisplay_as_udebug!(char);
+impl uDebug for char {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("'")?;
+        if is_escaped(*self, '\'') {
+            write_escaped(f, *self)?;
+        } else {
+            f.write_char(*self)?;
+        }
+        f.write_str("'")
+    }
+}
 
 impl uDisplayPadded for char {
     fn fmt_padded<W>(
@@ -58,6 +74,24 @@ impl uDisplayPadded for char {
     }
 }
 
+impl uDisplayPrecision for char {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut buf = [0_u8; 4];
+        let s: &str = (*self).encode_utf8(&mut buf);
+        s.fmt_precision(fmt, prefix, padding, pad_char, behind)
+    }
+}
+
 impl uDisplay for str {
     #[inline(always)]
     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
@@ -68,7 +102,64 @@ impl uDisplay for str {
     }
 }
 
-udisplay_as_udebug!(str);
+// Quoted and escaped like `core::fmt`, runs of chars which need no escaping are written at once
+impl uDebug for str {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("\"")?;
+        let mut start = 0;
+        for (i, c) in self.char_indices() {
+            if !is_escaped(c, '"') {
+                continue;
+            }
+            f.write_str(self.get(start..i).unwrap_or_default())?;
+            write_escaped(f, c)?;
+            start = i + c.len_utf8();
+        }
+        f.write_str(self.get(start..).unwrap_or_default())?;
+        f.write_str("\"")
+    }
+}
+
+/// Whether `c` is escaped by `{:?}` within the given quotes. Unlike `core::fmt`, other
+/// non-printable chars and combining marks are written as they are, as the unicode tables of
+/// `char::escape_debug` contain panicking branches.
+fn is_escaped(c: char, quote: char) -> bool {
+    c == quote || c == '\\' || c.is_control()
+}
+
+/// Writes the escape sequence of `c`, e.g. `\n` or `\u{1b}`
+fn write_escaped<W>(f: &mut Formatter<'_, W>, c: char) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let escape = match c {
+        '\\' => "\\\\",
+        '"' => "\\\"",
+        '\'' => "\\'",
+        '\n' => "\\n",
+        '\r' => "\\r",
+        '\t' => "\\t",
+        '\0' => "\\0",
+        _ => {
+            // hex digits without leading zeros like `core::fmt`
+            let c = c as u32;
+            let digits = (32 - (c | 1).leading_zeros()).div_ceil(4);
+            f.write_str("\\u{")?;
+            for i in (0..digits).rev() {
+                let digit = (c >> (4 * i)) as u8 & 0xf;
+                f.write_char(match digit {
+                    0..=9 => (b'0' + digit) as char,
+                    _ => (b'a' + digit - 10) as char,
+                })?;
+            }
+            "}"
+        }
+    };
+    f.write_str(escape)
+}
 
 impl uDisplayPadded for &str {
     fn fmt_padded<W>(
@@ -84,7 +175,27 @@ impl uDisplayPadded for &str {
             Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
             _ => padding,
         };
-        fmt.write_padded(*self, pad_char, padding)
+        fmt.write_padded(self, pad_char, padding)
+    }
+}
+
+impl uDisplayPrecision for &str {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let padding = match padding {
+            Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
+            _ => padding,
+        };
+        fmt.write_truncated(self, behind, prefix, pad_char, padding)
     }
 }
 
@@ -114,6 +225,66 @@ where
     }
 }
 
+impl<T> uDisplayHex for &'_ T
+where
+    T: uDisplayHex + ?Sized,
+{
+    #[inline(always)]
+    fn fmt_hex<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        cmd: char,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <T as uDisplayHex>::fmt_hex(self, fmt, prefix, cmd, padding, pad_char)
+    }
+}
+
+impl<T> uDisplayPadded for &'_ T
+where
+    T: uDisplayPadded + ?Sized,
+{
+    #[inline(always)]
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <T as uDisplayPadded>::fmt_padded(self, fmt, padding, pad_char)
+    }
+}
+
+impl<T> uDisplayPrecision for &'_ T
+where
+    T: uDisplayPrecision + ?Sized,
+{
+    const PREFIX: bool = T::PREFIX;
+
+    #[inline(always)]
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <T as uDisplayPrecision>::fmt_precision(self, fmt, prefix, padding, pad_char, behind)
+    }
+}
+
 impl<T> uDebug for &'_ mut T
 where
     T: uDebug + ?Sized,
@@ -189,7 +360,7 @@ impl<const N: usize> uDebug for heapless07::String<N> {
     where
         W: uWrite + ?Sized,
     {
-        <heapless07::String<N> as uDisplay>::fmt(self, f)
+        <str as uDebug>::fmt(self, f)
     }
 }
 
@@ -208,7 +379,28 @@ impl<const N: usize> uDisplayPadded for heapless07::String<N> {
             Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
             _ => padding,
         };
-        fmt.write_padded(&*self, pad_char, padding)
+        fmt.write_padded(self, pad_char, padding)
+    }
+}
+
+#[cfg(feature = "heapless07")]
+impl<const N: usize> uDisplayPrecision for heapless07::String<N> {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let padding = match padding {
+            Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
+            _ => padding,
+        };
+        fmt.write_truncated(self, behind, prefix, pad_char, padding)
     }
 }
 
@@ -230,7 +422,7 @@ impl<const N: usize> uDebug for heapless08::String<N> {
     where
         W: uWrite + ?Sized,
     {
-        <heapless08::String<N> as uDisplay>::fmt(self, f)
+        <str as uDebug>::fmt(self, f)
     }
 }
 
@@ -249,7 +441,28 @@ impl<const N: usize> uDisplayPadded for heapless08::String<N> {
             Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
             _ => padding,
         };
-        fmt.write_padded(&*self, pad_char, padding)
+        fmt.write_padded(self, pad_char, padding)
+    }
+}
+
+#[cfg(feature = "heapless08")]
+impl<const N: usize> uDisplayPrecision for heapless08::String<N> {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let padding = match padding {
+            Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
+            _ => padding,
+        };
+        fmt.write_truncated(self, behind, prefix, pad_char, padding)
     }
 }
 
@@ -271,7 +484,7 @@ impl<const N: usize, L: heapless09::LenType> uDebug for heapless09::String<N, L>
     where
         W: uWrite + ?Sized,
     {
-        <heapless09::String<N, L> as uDisplay>::fmt(self, f)
+        <str as uDebug>::fmt(self, f)
     }
 }
 
@@ -290,6 +503,27 @@ impl<const N: usize, L: heapless09::LenType> uDisplayPadded for heapless09::Stri
             Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
             _ => padding,
         };
-        fmt.write_padded(&*self, pad_char, padding)
+        fmt.write_padded(self, pad_char, padding)
+    }
+}
+
+#[cfg(feature = "heapless09")]
+impl<const N: usize, L: heapless09::LenType> uDisplayPrecision for heapless09::String<N, L> {
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let padding = match padding {
+            Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
+            _ => padding,
+        };
+        fmt.write_truncated(self, behind, prefix, pad_char, padding)
     }
 }
@@ -1,10 +1,47 @@
 use crate::{
-    uDebug, uDisplay, uDisplayFloat, uWrite, udisplay_as_udebug, Convert, Formatter, Padding,
+    decimal::{self, decode_f32, decode_f64, Decimal, F32_WORDS, F64_WORDS},
+    uDebug, uDisplay, uDisplayFloat, uDisplayPadded, uDisplayPrecision, uWrite, udisplay_as_udebug,
+    Convert, Formatter, Overflow, Padding,
 };
 
 // max 2**32 4_294_967_296 (10 digits) + 6 digits right dp + '.' + '-' => 18 digits max
 const BUF_LEN: usize = 18;
 
+// Numbers up to this magnitude and precision are converted with u32 arithmetic and fit into
+// BUF_LEN
+const F32_FAST_MAX: f32 = 8_388_608.0; // 2**23
+const F64_FAST_MAX: f64 = 4_294_967_295.0; // u32::MAX
+const FAST_DECIMAL_PLACES: usize = 6;
+
+// Decimal places of `{}`, `{:8}` and `{:+}`
+const DISPLAY_DECIMAL_PLACES: usize = 3;
+
+// Enough to distinguish all f64 values, also supported for f32 (then showing the exact value)
+const MAX_DECIMAL_PLACES: usize = 17;
+
+// Numbers up to the fast magnitude with a higher precision
+// 10 digits + 17 digits right dp + '.' + '-' => 29 digits max
+const PRECISE_BUF_LEN: usize = 29;
+
+// f32::MAX has 39 integer digits + '.' + 17 digits right dp + '-' => 58 digits max
+const F32_LARGE_BUF_LEN: usize = 58;
+
+// f64::MAX has 309 integer digits + '.' + 17 digits right dp + '-' => 328 digits max
+const F64_LARGE_BUF_LEN: usize = 328;
+
+// '-' + 18 digits + '.' + "e-324" + 1 spare => 26 digits max
+const EXP_BUF_LEN: usize = 26;
+
+// The shortest representation has at most 17 significant digits. Numbers from 1e-7 to 1e16 need
+// '-' + "0." + 6 zeros + 17 digits => 26 digits max
+const SHORTEST_BUF_LEN: usize = 26;
+const SHORTEST_MIN: f64 = 1e-7;
+const SHORTEST_MAX: f64 = 1e16;
+
+// The smallest subnormal numbers need "0." + 44 (f32) or 323 (f64) zeros + 1 digit + '-'
+const F32_SHORTEST_BUF_LEN: usize = 48;
+const F64_SHORTEST_BUF_LEN: usize = 327;
+
 impl uDisplayFloat for f32 {
     fn fmt_float<W>(
         &self,
@@ -16,11 +53,65 @@ impl uDisplayFloat for f32 {
     where
         W: uWrite + ?Sized,
     {
-        let convert = Convert::<BUF_LEN>::from_f32(*self, behind);
-        if let Ok(convert) = convert {
-            fmt.write_padded(convert.as_str(), pad_char, padding)?;
+        let Some(behind) = check_precision(fmt, behind) else {
+            return fmt.write_overflow(pad_char, padding);
+        };
+        if self.abs() > F32_FAST_MAX {
+            return fmt_large::<W, F32_LARGE_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f32(*self, behind)
+            });
+        }
+        if behind > FAST_DECIMAL_PLACES {
+            return fmt_large::<W, PRECISE_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f32(*self, behind)
+            });
+        }
+        match Convert::<BUF_LEN>::from_f32(*self, behind) {
+            Ok(convert) => fmt.write_padded_number("", convert.as_str(), pad_char, padding),
+            Err(_) => fmt.write_overflow(pad_char, padding),
+        }
+    }
+
+    fn fmt_exp<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+        behind: Option<usize>,
+        upper: bool,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut convert = Convert::<EXP_BUF_LEN>::new(b' ');
+        let res = match behind {
+            Some(behind) => match check_precision(fmt, behind) {
+                Some(behind) => convert.f32_exp(*self, behind, upper),
+                None => Err(()),
+            },
+            None => convert.f32_exp_shortest(*self, upper),
+        };
+        write_converted(fmt, padding, pad_char, res.map(|_| convert.as_str()))
+    }
+
+    fn fmt_shortest<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let abs = self.abs() as f64;
+        if abs != 0.0 && !(SHORTEST_MIN..SHORTEST_MAX).contains(&abs) {
+            return fmt_large::<W, F32_SHORTEST_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f32_shortest(*self)
+            });
         }
-        Ok(()) // Silently ignore errors during formatting
+        let mut convert = Convert::<SHORTEST_BUF_LEN>::new(b' ');
+        let res = convert.f32_shortest(*self);
+        write_converted(fmt, padding, pad_char, res.map(|_| convert.as_str()))
     }
 }
 
@@ -29,16 +120,55 @@ impl uDisplay for f32 {
     where
         W: uWrite + ?Sized,
     {
-        let convert = Convert::<BUF_LEN>::from_f32(*self, 3);
-        if let Ok(convert) = convert {
-            fmt.write_str(convert.as_str())?;
+        if self.abs() > F32_FAST_MAX {
+            return fmt_large::<W, F32_LARGE_BUF_LEN>(fmt, Padding::Usual(0), ' ', |c| {
+                c.f32(*self, DISPLAY_DECIMAL_PLACES)
+            });
+        }
+        match Convert::<BUF_LEN>::from_f32(*self, DISPLAY_DECIMAL_PLACES) {
+            Ok(convert) => fmt.write_str(convert.as_str()),
+            Err(_) => fmt.write_overflow(' ', Padding::Usual(0)),
         }
-        Ok(()) // Silently ignore errors during formatting
     }
 }
 
 udisplay_as_udebug!(f32);
 
+// Without a precision, `{:8}` and `{:+}` write the same digits as `{}` with padding and sign
+impl uDisplayPadded for f32 {
+    #[inline(always)]
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.fmt_float(fmt, padding, pad_char, DISPLAY_DECIMAL_PLACES)
+    }
+}
+
+impl uDisplayPrecision for f32 {
+    const PREFIX: bool = false;
+
+    #[inline(always)]
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        _prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.fmt_float(fmt, padding, pad_char, behind)
+    }
+}
+
 impl uDisplayFloat for f64 {
     fn fmt_float<W>(
         &self,
@@ -50,11 +180,65 @@ impl uDisplayFloat for f64 {
     where
         W: uWrite + ?Sized,
     {
-        let convert = Convert::<BUF_LEN>::from_f64(*self, behind);
-        if let Ok(convert) = convert {
-            fmt.write_padded(convert.as_str(), pad_char, padding)?;
+        let Some(behind) = check_precision(fmt, behind) else {
+            return fmt.write_overflow(pad_char, padding);
+        };
+        if self.abs() > F64_FAST_MAX {
+            return fmt_large::<W, F64_LARGE_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f64(*self, behind)
+            });
+        }
+        if behind > FAST_DECIMAL_PLACES {
+            return fmt_large::<W, PRECISE_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f64(*self, behind)
+            });
+        }
+        match Convert::<BUF_LEN>::from_f64(*self, behind) {
+            Ok(convert) => fmt.write_padded_number("", convert.as_str(), pad_char, padding),
+            Err(_) => fmt.write_overflow(pad_char, padding),
         }
-        Ok(()) // Silently ignore errors during formatting
+    }
+
+    fn fmt_exp<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+        behind: Option<usize>,
+        upper: bool,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let mut convert = Convert::<EXP_BUF_LEN>::new(b' ');
+        let res = match behind {
+            Some(behind) => match check_precision(fmt, behind) {
+                Some(behind) => convert.f64_exp(*self, behind, upper),
+                None => Err(()),
+            },
+            None => convert.f64_exp_shortest(*self, upper),
+        };
+        write_converted(fmt, padding, pad_char, res.map(|_| convert.as_str()))
+    }
+
+    fn fmt_shortest<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let abs = self.abs();
+        if abs != 0.0 && !(SHORTEST_MIN..SHORTEST_MAX).contains(&abs) {
+            return fmt_large::<W, F64_SHORTEST_BUF_LEN>(fmt, padding, pad_char, |c| {
+                c.f64_shortest(*self)
+            });
+        }
+        let mut convert = Convert::<SHORTEST_BUF_LEN>::new(b' ');
+        let res = convert.f64_shortest(*self);
+        write_converted(fmt, padding, pad_char, res.map(|_| convert.as_str()))
     }
 }
 
@@ -63,16 +247,120 @@ impl uDisplay for f64 {
     where
         W: uWrite + ?Sized,
     {
-        let convert = Convert::<BUF_LEN>::from_f64(*self, 3);
-        if let Ok(convert) = convert {
-            fmt.write_str(convert.as_str())?;
+        if self.abs() > F64_FAST_MAX {
+            return fmt_large::<W, F64_LARGE_BUF_LEN>(fmt, Padding::Usual(0), ' ', |c| {
+                c.f64(*self, DISPLAY_DECIMAL_PLACES)
+            });
+        }
+        match Convert::<BUF_LEN>::from_f64(*self, DISPLAY_DECIMAL_PLACES) {
+            Ok(convert) => fmt.write_str(convert.as_str()),
+            Err(_) => fmt.write_overflow(' ', Padding::Usual(0)),
         }
-        Ok(()) // Silently ignore errors during formatting
     }
 }
 
 udisplay_as_udebug!(f64);
 
+// Without a precision, `{:8}` and `{:+}` write the same digits as `{}` with padding and sign
+impl uDisplayPadded for f64 {
+    #[inline(always)]
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.fmt_float(fmt, padding, pad_char, DISPLAY_DECIMAL_PLACES)
+    }
+}
+
+impl uDisplayPrecision for f64 {
+    const PREFIX: bool = false;
+
+    #[inline(always)]
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        _prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.fmt_float(fmt, padding, pad_char, behind)
+    }
+}
+
+// Limits the precision to the supported maximum, if the overflow policy of the formatter is
+// `Overflow::Saturate`. `None` means that the value has to be reported as overflow.
+fn check_precision<W>(fmt: &Formatter<'_, W>, behind: usize) -> Option<usize>
+where
+    W: uWrite + ?Sized,
+{
+    if behind <= MAX_DECIMAL_PLACES {
+        Some(behind)
+    } else if fmt.overflow() == Overflow::Saturate {
+        Some(MAX_DECIMAL_PLACES)
+    } else {
+        None
+    }
+}
+
+// Whether `mant * 2^exp * 10^decimal_places` lies exactly halfway between two integers. The fast
+// conversion rounds these ties up, so they are left to `float_fixed`, which rounds half to even
+// like `core::fmt`.
+fn is_tie(mant: u64, exp: i32, decimal_places: usize) -> bool {
+    // the value is `mant * 5^decimal_places / 2^shift`
+    let shift = -(exp + decimal_places as i32);
+    // `mant * 5^6` is below 2^67, so a tie needs a shift of at most 67
+    if !(1..=67).contains(&shift) {
+        return false;
+    }
+    let mut scaled = mant as u128;
+    for _ in 0..decimal_places {
+        scaled *= 5;
+    }
+    scaled & ((1 << shift) - 1) == 1 << (shift - 1)
+}
+
+// Writes the result of a conversion or reports the overflow
+fn write_converted<W>(
+    fmt: &mut Formatter<'_, W>,
+    padding: Padding,
+    pad_char: char,
+    res: Result<&str, ()>,
+) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    match res {
+        Ok(s) => fmt.write_padded_number("", s, pad_char, padding),
+        Err(_) => fmt.write_overflow(pad_char, padding),
+    }
+}
+
+// Large numbers need a buffer of several hundred bytes. It is kept in a separate stack frame, so
+// that the common case of small numbers does not pay for it.
+#[inline(never)]
+fn fmt_large<W, const CAP: usize>(
+    fmt: &mut Formatter<'_, W>,
+    padding: Padding,
+    pad_char: char,
+    conv: impl FnOnce(&mut Convert<CAP>) -> Result<(), ()>,
+) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let mut convert = Convert::<CAP>::new(b' ');
+    let res = conv(&mut convert);
+    write_converted(fmt, padding, pad_char, res.map(|_| convert.as_str()))
+}
+
 impl<const CAP: usize> Convert<CAP> {
     /// Converts a f32 number into a string with the specified precision
     ///
@@ -126,9 +414,9 @@ impl<const CAP: usize> Convert<CAP> {
         if len > CAP {
             return Err(());
         }
-        let next_idx = self.idx - len;
+        let next_idx = self.idx.checked_sub(len).ok_or(())?;
         self.f32(f, decimal_places)?;
-        self.idx = next_idx;
+        self.idx = self.idx.min(next_idx);
         Ok(())
     }
 
@@ -148,13 +436,17 @@ impl<const CAP: usize> Convert<CAP> {
         if len > CAP {
             return Err(());
         }
-        let next_idx = self.idx - len;
+        let next_idx = self.idx.checked_sub(len).ok_or(())?;
         self.f64(f, decimal_places)?;
-        self.idx = next_idx;
+        self.idx = self.idx.min(next_idx);
         Ok(())
     }
 
     /// Appends a f32 number with the specified precision
+    ///
+    /// The whole range of f32 is supported, but large numbers need a correspondingly large buffer.
+    /// Up to 17 decimal places are possible, beyond 9 significant digits they show the exact
+    /// binary value of the number.
     pub fn f32(&mut self, f: f32, decimal_places: usize) -> Result<(), ()> {
         // General checks for validity and overflow
         if f.is_nan() {
@@ -162,38 +454,50 @@ impl<const CAP: usize> Convert<CAP> {
             return Ok(());
         }
 
-        if f > 8388608.0 {
-            // 2**23
-            self.write_str("ovfl")?;
+        if f.is_infinite() {
+            self.write_str(if f > 0.0 { "inf" } else { "-inf" })?;
             return Ok(());
         }
 
-        if f < -8388608.0 {
-            // 2**23
-            self.write_str("-ovfl")?;
-            return Ok(());
+        if decimal_places > MAX_DECIMAL_PLACES {
+            return Err(());
         }
 
-        if decimal_places > 6 {
-            return Err(());
+        let (mant, exp) = decode_f32(f);
+        if decimal_places > FAST_DECIMAL_PLACES
+            || !(-F32_FAST_MAX..=F32_FAST_MAX).contains(&f)
+            || is_tie(mant, exp, decimal_places)
+        {
+            return self.float_fixed::<F32_WORDS>(mant, exp, f.is_sign_negative(), decimal_places);
         }
 
         const MUL_TAB: [f32; 7] = [1.0, 10.0, 100.0, 1_000.0, 10_000.0, 100_000.0, 1_000_000.0];
         const ADD_TAB: [f32; 7] = [0.5, 0.05, 0.005, 0.000_5, 0.000_05, 0.000_005, 0.000_000_5];
 
         let (f, is_neg) = if f.is_sign_negative() {
-            ((-f) + ADD_TAB[decimal_places as usize], true)
+            ((-f) + ADD_TAB[decimal_places], true)
         } else {
-            (f + ADD_TAB[decimal_places as usize], false)
+            (f + ADD_TAB[decimal_places], false)
         };
 
         let left = f as u32;
-        let right = ((f - (left as f32)) * MUL_TAB[decimal_places as usize]) as u32;
+        let right = ((f - (left as f32)) * MUL_TAB[decimal_places]) as u32;
 
         self.float_as_str(left, right, decimal_places, is_neg)
     }
 
     /// Appends a f64 number with the specified precision
+    ///
+    /// The whole range of f64 is supported, but large numbers need a correspondingly large buffer.
+    /// Up to 17 decimal places are possible.
+    ///
+    /// ```
+    ///     use efmt::Convert;
+    ///
+    ///     let mut conv = Convert::<30>::new(b' ');
+    ///     conv.f64(-1.5e20, 2).unwrap();
+    ///     assert_eq!("-150000000000000000000.00", conv.as_str());
+    /// ```
     pub fn f64(&mut self, f: f64, decimal_places: usize) -> Result<(), ()> {
         // General checks for validity and overflow
         if f.is_nan() {
@@ -201,37 +505,318 @@ impl<const CAP: usize> Convert<CAP> {
             return Ok(());
         }
 
-        if f > 4_294_967_295.0 {
-            // u32::MAX
-            self.write_str("ovfl")?;
+        if f.is_infinite() {
+            self.write_str(if f > 0.0 { "inf" } else { "-inf" })?;
             return Ok(());
         }
 
-        if f < -4_294_967_295.0 {
-            // u32::MAX
-            self.write_str("-ovfl")?;
-            return Ok(());
+        if decimal_places > MAX_DECIMAL_PLACES {
+            return Err(());
         }
 
-        if decimal_places > 6 {
-            return Err(());
+        let (mant, exp) = decode_f64(f);
+        if decimal_places > FAST_DECIMAL_PLACES
+            || !(-F64_FAST_MAX..=F64_FAST_MAX).contains(&f)
+            || is_tie(mant, exp, decimal_places)
+        {
+            return self.float_fixed::<F64_WORDS>(mant, exp, f.is_sign_negative(), decimal_places);
         }
 
         const MUL_TAB: [f64; 7] = [1.0, 10.0, 100.0, 1_000.0, 10_000.0, 100_000.0, 1_000_000.0];
         const ADD_TAB: [f64; 7] = [0.5, 0.05, 0.005, 0.000_5, 0.000_05, 0.000_005, 0.000_000_5];
 
         let (f, is_neg) = if f.is_sign_negative() {
-            ((-f) + ADD_TAB[decimal_places as usize], true)
+            ((-f) + ADD_TAB[decimal_places], true)
         } else {
-            (f + ADD_TAB[decimal_places as usize], false)
+            (f + ADD_TAB[decimal_places], false)
         };
 
         let left = f as u32;
-        let right = ((f - (left as f64)) * MUL_TAB[decimal_places as usize]) as u32;
+        let right = ((f - (left as f64)) * MUL_TAB[decimal_places]) as u32;
 
         self.float_as_str(left, right, decimal_places, is_neg)
     }
 
+    /// Appends a f32 number in exponential representation with the specified precision
+    ///
+    /// ```
+    ///     use efmt::Convert;
+    ///
+    ///     let mut conv = Convert::<20>::new(b' ');
+    ///     conv.f32_exp(0.000_012_5, 2, false).unwrap();
+    ///     assert_eq!("1.25e-5", conv.as_str());
+    /// ```
+    pub fn f32_exp(&mut self, f: f32, decimal_places: usize, upper: bool) -> Result<(), ()> {
+        let digits = decimal_places.checked_add(1).ok_or(())?;
+        self.f32_exp_digits(f, digits, upper)
+    }
+
+    /// Appends a f64 number in exponential representation with the specified precision
+    ///
+    /// ```
+    ///     use efmt::Convert;
+    ///
+    ///     let mut conv = Convert::<20>::new(b' ');
+    ///     conv.f64_exp(1_234_567_890_123.0, 3, true).unwrap();
+    ///     assert_eq!("1.235E12", conv.as_str());
+    /// ```
+    pub fn f64_exp(&mut self, f: f64, decimal_places: usize, upper: bool) -> Result<(), ()> {
+        let digits = decimal_places.checked_add(1).ok_or(())?;
+        self.f64_exp_digits(f, digits, upper)
+    }
+
+    /// Appends the shortest representation of a f32 number, which reads back to the same number
+    ///
+    /// Like `core::fmt`, no exponent is used, so small and large numbers need a large buffer.
+    ///
+    /// ```
+    ///     use efmt::Convert;
+    ///
+    ///     let mut conv = Convert::<20>::new(b' ');
+    ///     conv.f32_shortest(0.1).unwrap();
+    ///     assert_eq!("0.1", conv.as_str());
+    /// ```
+    pub fn f32_shortest(&mut self, f: f32) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f32(f);
+        let lower_closer = mant == 1 << 23 && exp > -149;
+        self.float_shortest::<F32_WORDS>(mant, exp, f.is_sign_negative(), lower_closer)
+    }
+
+    /// Appends the shortest representation of a f64 number, which reads back to the same number
+    ///
+    /// Like `core::fmt`, no exponent is used, so small and large numbers need a large buffer.
+    ///
+    /// ```
+    ///     use efmt::Convert;
+    ///
+    ///     let mut conv = Convert::<20>::new(b' ');
+    ///     conv.f64_shortest(-1234.5e-3).unwrap();
+    ///     assert_eq!("-1.2345", conv.as_str());
+    /// ```
+    pub fn f64_shortest(&mut self, f: f64) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f64(f);
+        let lower_closer = mant == 1 << 52 && exp > -1074;
+        self.float_shortest::<F64_WORDS>(mant, exp, f.is_sign_negative(), lower_closer)
+    }
+
+    fn f32_exp_digits(&mut self, f: f32, digits: usize, upper: bool) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f32(f);
+        self.float_exp::<F32_WORDS>(mant, exp, f.is_sign_negative(), digits, upper)
+    }
+
+    fn f64_exp_digits(&mut self, f: f64, digits: usize, upper: bool) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f64(f);
+        self.float_exp::<F64_WORDS>(mant, exp, f.is_sign_negative(), digits, upper)
+    }
+
+    fn f32_exp_shortest(&mut self, f: f32, upper: bool) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f32(f);
+        let lower_closer = mant == 1 << 23 && exp > -149;
+        self.float_exp_shortest::<F32_WORDS>(mant, exp, f.is_sign_negative(), lower_closer, upper)
+    }
+
+    fn f64_exp_shortest(&mut self, f: f64, upper: bool) -> Result<(), ()> {
+        if f.is_nan() {
+            return self.write_str("NaN");
+        }
+        if f.is_infinite() {
+            return self.write_str(if f < 0.0 { "-inf" } else { "inf" });
+        }
+        let (mant, exp) = decode_f64(f);
+        let lower_closer = mant == 1 << 52 && exp > -1074;
+        self.float_exp_shortest::<F64_WORDS>(mant, exp, f.is_sign_negative(), lower_closer, upper)
+    }
+
+    // Writes `mant * 2^exp` with the given number of significant digits in the form `d.ddde-x`.
+    //
+    // The digits are generated from left to right, so they are first placed in the free part of
+    // the buffer, leaving enough space for the exponent. After the exponent has been written, the
+    // digits are moved into place from right to left.
+    fn float_exp<const N: usize>(
+        &mut self,
+        mant: u64,
+        exp: i32,
+        is_neg: bool,
+        digits: usize,
+        upper: bool,
+    ) -> Result<(), ()> {
+        const EXP_LEN: usize = 5; // "e-324"
+        let start = self.idx.checked_sub(digits + EXP_LEN + 1).ok_or(())?;
+        let buf = self.buf.get_mut(start..start + digits).ok_or(())?;
+
+        let mut e = 0;
+        if mant == 0 {
+            buf.fill(b'0');
+        } else {
+            let mut decimal = Decimal::<N>::new(mant, exp);
+            e = decimal.exp() - 1;
+            if decimal.write_digits(buf) {
+                if let Some(first) = buf.first_mut() {
+                    *first = b'1';
+                }
+                e += 1;
+            }
+        }
+
+        self.i32(e)?;
+        self.write_u8(if upper { b'E' } else { b'e' })?;
+        for i in (0..digits).rev() {
+            let d = self.buf.get(start + i).copied().ok_or(())?;
+            self.write_u8(d)?;
+            if i == 1 {
+                self.write_u8(b'.')?;
+            }
+        }
+        self.write_sign(is_neg, mant == 0)
+    }
+
+    // Writes the shortest digits of `mant * 2^exp`, which read back to the same number, in the
+    // form `d.ddde-x`
+    fn float_exp_shortest<const N: usize>(
+        &mut self,
+        mant: u64,
+        exp: i32,
+        is_neg: bool,
+        lower_closer: bool,
+        upper: bool,
+    ) -> Result<(), ()> {
+        let mut digits = [b'0'; 17];
+        let (n, k) = match mant {
+            0 => (1, 1),
+            _ => decimal::shortest::<N>(mant, exp, lower_closer, &mut digits),
+        };
+
+        self.i32(k - 1)?;
+        self.write_u8(if upper { b'E' } else { b'e' })?;
+        for (i, d) in digits.get(..n).unwrap_or(&[]).iter().enumerate().rev() {
+            self.write_u8(*d)?;
+            if i == 1 {
+                self.write_u8(b'.')?;
+            }
+        }
+        self.write_sign(is_neg, mant == 0)
+    }
+
+    // Writes the shortest digits of `mant * 2^exp` without exponent, i.e. with leading or trailing
+    // zeros as required
+    fn float_shortest<const N: usize>(
+        &mut self,
+        mant: u64,
+        exp: i32,
+        is_neg: bool,
+        lower_closer: bool,
+    ) -> Result<(), ()> {
+        let mut digits = [b'0'; 17];
+        let (n, k) = match mant {
+            0 => (1, 1),
+            _ => decimal::shortest::<N>(mant, exp, lower_closer, &mut digits),
+        };
+
+        let write_digits = |conv: &mut Self, range: core::ops::Range<usize>| {
+            for d in digits.get(range).unwrap_or(&[]).iter().rev() {
+                conv.write_u8(*d)?;
+            }
+            Ok(())
+        };
+        if k <= 0 {
+            write_digits(self, 0..n)?;
+            for _ in 0..k.unsigned_abs() {
+                self.write_u8(b'0')?;
+            }
+            self.write_str("0.")?;
+        } else if (k as usize) < n {
+            write_digits(self, k as usize..n)?;
+            self.write_u8(b'.')?;
+            write_digits(self, 0..k as usize)?;
+        } else {
+            for _ in n..k as usize {
+                self.write_u8(b'0')?;
+            }
+            write_digits(self, 0..n)?;
+        }
+        self.write_sign(is_neg, mant == 0)
+    }
+
+    // Writes `mant * 2^exp` with `decimal_places` digits right of the decimal point, rounded half
+    // to even. Like in `float_exp`, the digits are generated from left to right into the free part
+    // of the buffer and are then moved into place.
+    fn float_fixed<const N: usize>(
+        &mut self,
+        mant: u64,
+        exp: i32,
+        is_neg: bool,
+        decimal_places: usize,
+    ) -> Result<(), ()> {
+        let mut decimal = match mant {
+            0 => None,
+            _ => Some(Decimal::<N>::new(mant, exp)),
+        };
+        let k = decimal.as_ref().map_or(1, |d| d.exp());
+        if k < 0 && k.unsigned_abs() as usize > decimal_places {
+            // less than half of the last digit, rounds to zero
+            decimal = None;
+        }
+
+        let int_digits = k.max(1) as usize;
+        let n = int_digits + decimal_places;
+        let start = self.idx.checked_sub(n + 2).ok_or(())?;
+        let buf = self.buf.get_mut(start..start + n).ok_or(())?;
+
+        let carry = match decimal.as_mut() {
+            Some(decimal) => {
+                decimal.set_exp(int_digits as i32);
+                decimal.write_digits(buf)
+            }
+            None => {
+                buf.fill(b'0');
+                false
+            }
+        };
+        let is_zero = !carry && buf.iter().all(|d| *d == b'0');
+
+        for i in (0..n).rev() {
+            let d = self.buf.get(start + i).copied().ok_or(())?;
+            self.write_u8(d)?;
+            if i == int_digits && decimal_places > 0 {
+                self.write_u8(b'.')?;
+            }
+        }
+        if carry {
+            self.write_u8(b'1')?;
+        }
+        self.write_sign(is_neg, is_zero)
+    }
+
     // Internally, the floating point number is displayed as two integers, whereby the location of
     // the decimal point is shown separately.
     //
@@ -243,13 +828,14 @@ impl<const CAP: usize> Convert<CAP> {
         decimal_places: usize,
         is_neg: bool,
     ) -> Result<(), ()> {
+        let is_zero = left == 0 && right == 0;
         let dp_idx = if decimal_places == 0 {
             None
         } else {
             if decimal_places > self.idx {
                 return Err(());
             }
-            Some(self.idx - decimal_places as usize)
+            Some(self.idx - decimal_places)
         };
 
         // Safety: This is necessary to avoid getting a panic branch
@@ -259,7 +845,7 @@ impl<const CAP: usize> Convert<CAP> {
         if let Some(dp_idx) = dp_idx {
             while self.idx > dp_idx {
                 let m = (right % 10) as u8;
-                right = right / 10;
+                right /= 10;
                 self.write_u8(m + b'0')?;
             }
             self.write_u8(b'.')?;
@@ -271,13 +857,19 @@ impl<const CAP: usize> Convert<CAP> {
         } else {
             while left > 0 {
                 let m = (left % 10) as u8;
-                left = left / 10;
+                left /= 10;
                 self.write_u8(m + b'0')?;
             }
         }
 
         // Add negativ sign if necessary
-        if is_neg {
+        self.write_sign(is_neg, is_zero)
+    }
+
+    // Writes the sign of a negative number. A negative number, which is displayed as zero, keeps
+    // its sign like in `core::fmt` unless the feature `no_negative_zero` is activated.
+    fn write_sign(&mut self, is_neg: bool, is_zero: bool) -> Result<(), ()> {
+        if is_neg && !(cfg!(feature = "no_negative_zero") && is_zero) {
             self.write_u8(b'-')?;
         }
         Ok(())
@@ -0,0 +1,100 @@
+use crate::{uDebug, uWrite, Formatter};
+
+// `uDebug` like the `Debug` impls of heapless, the bounds are the ones of the respective iterators
+macro_rules! heapless {
+    ($kind:ident; $([$($g:tt)*] $type:ty where [$($bound:tt)*], #[$cfg:meta]);+ $(;)?) => {
+        $(
+            #[$cfg]
+            impl<$($g)*> uDebug for $type
+            where
+                $($bound)*
+            {
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    heapless!(@$kind self, f)
+                }
+            }
+        )+
+    };
+    (@slice $self:ident, $f:ident) => {
+        uDebug::fmt($self.as_slice(), $f)
+    };
+    (@list $self:ident, $f:ident) => {
+        $f.debug_list()?.entries($self.iter())?.finish()
+    };
+    (@map $self:ident, $f:ident) => {
+        $f.debug_map()?.entries($self)?.finish()
+    };
+    (@set $self:ident, $f:ident) => {
+        $f.debug_set()?.entries($self)?.finish()
+    };
+}
+
+heapless!(slice;
+    [T, const N: usize] heapless07::Vec<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless07")];
+    [T, const N: usize] heapless07::HistoryBuffer<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless07")];
+    [T, const N: usize] heapless08::Vec<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless08")];
+    [T, const N: usize] heapless08::HistoryBuffer<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless08")];
+    [T, const N: usize, L: heapless09::LenType] heapless09::Vec<T, N, L>
+        where [T: uDebug], #[cfg(feature = "heapless09")];
+    [T, const N: usize] heapless09::HistoryBuf<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless09")];
+);
+
+heapless!(list;
+    [T, const N: usize] heapless07::Deque<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless07")];
+    [T, K, const N: usize] heapless07::BinaryHeap<T, K, N>
+        where [T: Ord + uDebug, K: heapless07::binary_heap::Kind], #[cfg(feature = "heapless07")];
+    [T, const N: usize] heapless08::Deque<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless08")];
+    [T, K, const N: usize] heapless08::BinaryHeap<T, K, N>
+        where [T: Ord + uDebug, K: heapless08::binary_heap::Kind], #[cfg(feature = "heapless08")];
+    [T, const N: usize] heapless09::Deque<T, N>
+        where [T: uDebug], #[cfg(feature = "heapless09")];
+    [T, K, const N: usize] heapless09::BinaryHeap<T, K, N>
+        where [T: Ord + uDebug, K: heapless09::binary_heap::Kind], #[cfg(feature = "heapless09")];
+);
+
+heapless!(map;
+    [K, V, const N: usize] heapless07::LinearMap<K, V, N>
+        where [K: Eq + uDebug, V: uDebug], #[cfg(feature = "heapless07")];
+    [K, V, S, const N: usize] heapless07::IndexMap<K, V, S, N>
+        where [
+            K: uDebug,
+            V: uDebug,
+            for<'a> &'a heapless07::IndexMap<K, V, S, N>: IntoIterator<Item = (&'a K, &'a V)>,
+        ], #[cfg(feature = "heapless07")];
+    [K, V, const N: usize] heapless08::LinearMap<K, V, N>
+        where [K: Eq + uDebug, V: uDebug], #[cfg(feature = "heapless08")];
+    [K, V, S, const N: usize] heapless08::IndexMap<K, V, S, N>
+        where [K: uDebug, V: uDebug], #[cfg(feature = "heapless08")];
+    [K, V, const N: usize] heapless09::LinearMap<K, V, N>
+        where [K: Eq + uDebug, V: uDebug], #[cfg(feature = "heapless09")];
+    [K, V, S, const N: usize] heapless09::IndexMap<K, V, S, N>
+        where [K: uDebug, V: uDebug], #[cfg(feature = "heapless09")];
+);
+
+heapless!(set;
+    [T, S, const N: usize] heapless07::IndexSet<T, S, N>
+        where [
+            T: uDebug,
+            for<'a> &'a heapless07::IndexSet<T, S, N>: IntoIterator<Item = &'a T>,
+        ], #[cfg(feature = "heapless07")];
+    [T, S, const N: usize] heapless08::IndexSet<T, S, N>
+        where [
+            T: Eq + core::hash::Hash + uDebug,
+            S: core::hash::BuildHasher,
+        ], #[cfg(feature = "heapless08")];
+    [T, S, const N: usize] heapless09::IndexSet<T, S, N>
+        where [
+            T: Eq + core::hash::Hash + uDebug,
+            S: core::hash::BuildHasher,
+        ], #[cfg(feature = "heapless09")];
+);
@@ -1,9 +1,43 @@
-use crate::{
-    uDebug, uDisplay, uDisplayHex, uDisplayPadded, uWrite, udisplay_as_udebug, Convert, Formatter,
-    Padding,
-};
+use crate::{uDebug, uDisplay, uDisplayHex, uDisplayPadded, uWrite, Convert, Formatter, Padding};
 use core::{slice, str};
 
+/// Writes an integer like `{}`, with the `x?` or `X?` flag like `{:x}`, in pretty mode like `{:#x}`
+pub(crate) fn fmt_debug<T, W>(n: &T, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+where
+    T: uDisplay + uDisplayHex,
+    W: uWrite + ?Sized,
+{
+    match f.debug_hex {
+        Some(upper) => {
+            let prefix = f.pretty;
+            n.fmt_hex(
+                f,
+                prefix,
+                if upper { 'X' } else { 'x' },
+                Padding::Usual(0),
+                ' ',
+            )
+        }
+        None => n.fmt(f),
+    }
+}
+
+macro_rules! debug_int {
+    ($($type:ty),+) => {
+        $(
+            impl uDebug for $type {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    fmt_debug(self, f)
+                }
+            }
+        )+
+    };
+}
+
 macro_rules! uxx {
     ($n:expr, $len:expr) => {{
         let mut buf = [0_u8; $len];
@@ -50,7 +84,7 @@ macro_rules! uxx_trait_impl {
                 W: uWrite + ?Sized,
             {
                 let s = uxx!(*self as $utype, $len);
-                fmt.write_padded(s, pad_char, padding)
+                fmt.write_padded_number("", s, pad_char, padding)
             }
         }
     };
@@ -69,12 +103,7 @@ uxx_trait_impl!(usize, 10);
 #[cfg(target_pointer_width = "64")]
 uxx_trait_impl!(usize, 20);
 
-udisplay_as_udebug!(u8);
-udisplay_as_udebug!(u16);
-udisplay_as_udebug!(u32);
-udisplay_as_udebug!(u64);
-udisplay_as_udebug!(u128);
-udisplay_as_udebug!(usize);
+debug_int!(u16, u32, u64, u128, usize);
 
 macro_rules! ixx {
     ($uxx:ty, $n:expr, $len:expr) => {{
@@ -139,7 +168,7 @@ macro_rules! ixx_trait_impl {
                 W: uWrite + ?Sized,
             {
                 let s = ixx!($utype, *self, $len);
-                fmt.write_padded(s, pad_char, padding)
+                fmt.write_padded_number("", s, pad_char, padding)
             }
         }
     };
@@ -158,61 +187,46 @@ ixx_trait_impl!(isize, usize, 11);
 #[cfg(target_pointer_width = "64")]
 ixx_trait_impl!(isize, usize, 21);
 
-udisplay_as_udebug!(i8);
-udisplay_as_udebug!(i16);
-udisplay_as_udebug!(i32);
-udisplay_as_udebug!(i64);
-udisplay_as_udebug!(i128);
-udisplay_as_udebug!(isize);
-
-const HEX_BUF_LEN: usize = 35;
+debug_int!(i8, i16, i32, i64, i128, isize);
 
+// Writes the digits of `$n` and the optional prefix right-aligned into a buffer, which is large
+// enough for the binary representation of `$utype` plus the prefix
 macro_rules! hex_oct_bin {
-    ($utype: ty, $n:expr, $upper: expr, $prefix: expr, $div: expr, $len: expr) => {{
-        let mut buf = [0_u8; HEX_BUF_LEN];
+    ($utype: ty, $n:expr, $upper: expr, $prefix: expr, $div: expr) => {{
+        const LEN: usize = <$utype>::BITS as usize + 2;
+        let mut buf = [0_u8; LEN];
         let ptr = &buf.as_mut_ptr().cast::<u8>();
         let mut n = $n;
         let c = if $upper { b'A' } else { b'a' };
-        let mut i = HEX_BUF_LEN - 1;
-        let mut len = $len;
+        let mut i = LEN;
         loop {
             let val = (n % $div) as u8;
             let d = if val < 10 { b'0' + val } else { c + val - 10 };
 
-            if i == 2 {
-                buf[HEX_BUF_LEN - 5..HEX_BUF_LEN].copy_from_slice(b" ovfl");
-                i = HEX_BUF_LEN - 5;
-                break;
-            } else {
-                // SAFETY: Since i >= 0 and below CAP, this access is secure. This construct is
-                // necessary because rust array accesses generate an undesired panicking branch.
-                unsafe { ptr.add(i).write_volatile(d) }
-            }
+            i -= 1;
+            // SAFETY: Since i >= 2 and below LEN, this access is secure. This construct is
+            // necessary because rust array accesses generate an undesired panicking branch.
+            unsafe { ptr.add(i).write_volatile(d) }
 
             n /= $div;
-            if n == 0 && len == 0 {
+            if n == 0 || i == 2 {
                 break;
-            } else {
-                i -= 1;
-                if len > 0 {
-                    len -= 1;
-                }
             }
         }
         if let Some(c) = $prefix {
             i -= 1;
-            // SAFETY: Since i >= 0 and below CAP, this access is secure. This construct is
+            // SAFETY: Since i >= 0 and below LEN, this access is secure. This construct is
             // necessary because rust array accesses generate an undesired panicking branch.
             unsafe { ptr.add(i).write_volatile(c) }
             i -= 1;
-            // SAFETY: Since i >= 0 and below CAP, this access is secure. This construct is
+            // SAFETY: Since i >= 0 and below LEN, this access is secure. This construct is
             // necessary because rust array accesses generate an undesired panicking branch.
             unsafe { ptr.add(i).write_volatile(b'0') }
         }
 
         // SAFETY: We only return characters here that we have previously initialised. This is
         // therefore safe and a new check for utf8 conformity is pointless.
-        unsafe { str::from_utf8_unchecked(slice::from_raw_parts(ptr.add(i), HEX_BUF_LEN - i)) }
+        unsafe { str::from_utf8_unchecked(slice::from_raw_parts(ptr.add(i), LEN - i)) }
     }};
 }
 
@@ -230,33 +244,13 @@ macro_rules! hex_trait_impl {
             where
                 W: uWrite + ?Sized,
             {
-                let (c, div) = match cmd {
-                    'o' => (b'o', 8),
-                    'b' => (b'b', 2),
-                    _ => (b'x', 16), // 'x', 'X'
+                let (pre, div) = match cmd {
+                    'o' => ("0o", 8),
+                    'b' => ("0b", 2),
+                    _ => ("0x", 16), // 'x', 'X'
                 };
-                let pre_char = if prefix { Some(c) } else { None };
-                let len = if pad_char == '0' {
-                    let len = match padding {
-                        Padding::LeftAligned(l) => l,
-                        Padding::RightAligned(l) => l,
-                        Padding::CenterAligned(l) => l,
-                        Padding::Usual(l) => l,
-                    };
-                    if prefix {
-                        len - 3
-                    } else {
-                        len - 1
-                    }
-                } else {
-                    0
-                };
-                let s = hex_oct_bin!($u_type, *self as $u_type, cmd == 'X', pre_char, div, len);
-                if len == 0 {
-                    fmt.write_padded(s, pad_char, padding)
-                } else {
-                    fmt.write_str(s)
-                }
+                let s = hex_oct_bin!($u_type, *self as $u_type, cmd == 'X', None, div);
+                fmt.write_padded_number(if prefix { pre } else { "" }, s, pad_char, padding)
             }
         }
     };
@@ -294,7 +288,7 @@ impl<T> uDebug for *const T {
     where
         W: uWrite + ?Sized,
     {
-        let s = hex_oct_bin!(u16, *self as u16, false, Some(b'x'), 16, 0);
+        let s = hex_oct_bin!(u16, *self as u16, false, Some(b'x'), 16);
         f.write_str(s)
     }
 
@@ -303,7 +297,7 @@ impl<T> uDebug for *const T {
     where
         W: uWrite + ?Sized,
     {
-        let s = hex_oct_bin!(u32, *self as u32, false, Some(b'x'), 16, 0);
+        let s = hex_oct_bin!(u32, *self as u32, false, Some(b'x'), 16);
         f.write_str(s)
     }
 
@@ -312,7 +306,7 @@ impl<T> uDebug for *const T {
     where
         W: uWrite + ?Sized,
     {
-        let s = hex_oct_bin!(u64, *self as u64, false, Some(b'x'), 16, 0);
+        let s = hex_oct_bin!(u64, *self as u64, false, Some(b'x'), 16);
         f.write_str(s)
     }
 }
@@ -416,7 +410,7 @@ impl<const CAP: usize> Convert<CAP> {
         let u = if negative {
             match i.checked_abs() {
                 Some(i) => i as u32,
-                None => <u32>::max_value() / 2 + 1,
+                None => u32::MAX / 2 + 1,
             }
         } else {
             i as u32
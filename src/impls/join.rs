@@ -0,0 +1,146 @@
+use crate::{
+    uDebug, uDisplay, uDisplayHex, uDisplayPadded, uDisplayPrecision, uWrite, Formatter, Padding,
+};
+
+/// Writes the items of an iterator, separated by a separator
+///
+/// The format spec is applied to each item, see [join].
+#[derive(Clone, Copy)]
+pub struct Separated<'a, I> {
+    iter: I,
+    separator: &'a str,
+}
+
+/// Creates an adapter to write the items of `iter` separated by `separator`
+///
+/// The iterator is cloned for each output. `{}`, `{:?}`, `{:08}`, `{:.2}` or `{:02x}` format each
+/// item, which has to implement the corresponding trait.
+///
+/// ```
+/// use efmt::{join, uformat};
+///
+/// let s = uformat!(100, "{}", join(&[1, 2, 3], ", ")).unwrap();
+/// assert_eq!("1, 2, 3", s.as_str());
+/// let s = uformat!(100, "{:02x}", join([0x1f_u8, 0x80], ":")).unwrap();
+/// assert_eq!("1f:80", s.as_str());
+/// let s = uformat!(100, "[{:>4}]", join((1..4).map(|i| i * i), "|")).unwrap();
+/// assert_eq!("[   1|   4|   9]", s.as_str());
+/// ```
+pub fn join<I>(iter: I, separator: &str) -> Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+{
+    Separated { iter, separator }
+}
+
+impl<I> Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+{
+    fn write_items<W>(
+        &self,
+        f: &mut Formatter<'_, W>,
+        mut write: impl FnMut(&I::Item, &mut Formatter<'_, W>) -> Result<(), W::Error>,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        for (i, item) in self.iter.clone().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            write(&item, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I> uDisplay for Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: uDisplay,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.write_items(f, |item, f| uDisplay::fmt(item, f))
+    }
+}
+
+impl<I> uDebug for Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.write_items(f, |item, f| uDebug::fmt(item, f))
+    }
+}
+
+impl<I> uDisplayPadded for Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: uDisplayPadded,
+{
+    fn fmt_padded<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.write_items(fmt, |item, f| item.fmt_padded(f, padding, pad_char))
+    }
+}
+
+impl<I> uDisplayPrecision for Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: uDisplayPrecision,
+{
+    const PREFIX: bool = I::Item::PREFIX;
+
+    fn fmt_precision<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.write_items(fmt, |item, f| {
+            item.fmt_precision(f, prefix, padding, pad_char, behind)
+        })
+    }
+}
+
+impl<I> uDisplayHex for Separated<'_, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: uDisplayHex,
+{
+    fn fmt_hex<W>(
+        &self,
+        fmt: &mut Formatter<'_, W>,
+        prefix: bool,
+        cmd: char,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.write_items(fmt, |item, f| {
+            item.fmt_hex(f, prefix, cmd, padding, pad_char)
+        })
+    }
+}
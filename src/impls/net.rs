@@ -0,0 +1,187 @@
+use core::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    str::from_utf8_unchecked,
+};
+
+use crate::{
+    uDebug, uDisplay, uDisplayHex, uDisplayPadded, uWrite, udisplay_as_udebug, Formatter, Padding,
+};
+
+impl uDisplay for Ipv4Addr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        for (i, octet) in self.octets().iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            <u8 as uDisplay>::fmt(octet, f)?;
+        }
+        Ok(())
+    }
+}
+
+// Canonical representation according to RFC 5952 like in `core::fmt`: the longest run of at least
+// two zero segments is compressed to `::`, IPv4-mapped addresses are written as `::ffff:a.b.c.d`
+impl uDisplay for Ipv6Addr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            f.write_str("::ffff:")?;
+            return uDisplay::fmt(&ipv4, f);
+        }
+
+        let segments = self.segments();
+        // start and length of the longest run of zeros, the first one wins
+        let (mut start, mut len) = (0, 0);
+        let mut current = 0;
+        for (i, &segment) in segments.iter().enumerate() {
+            if segment == 0 {
+                current += 1;
+                if current > len {
+                    start = i + 1 - current;
+                    len = current;
+                }
+            } else {
+                current = 0;
+            }
+        }
+
+        if len > 1 {
+            write_segments(f, segments.get(..start).unwrap_or_default())?;
+            f.write_str("::")?;
+            write_segments(f, segments.get(start + len..).unwrap_or_default())
+        } else {
+            write_segments(f, &segments)
+        }
+    }
+}
+
+/// Writes the segments in hex, separated by `:`
+fn write_segments<W>(f: &mut Formatter<'_, W>, segments: &[u16]) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    for (i, segment) in segments.iter().enumerate() {
+        if i > 0 {
+            f.write_str(":")?;
+        }
+        segment.fmt_hex(f, false, 'x', Padding::Usual(0), ' ')?;
+    }
+    Ok(())
+}
+
+impl uDisplay for IpAddr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            IpAddr::V4(ip) => uDisplay::fmt(ip, f),
+            IpAddr::V6(ip) => uDisplay::fmt(ip, f),
+        }
+    }
+}
+
+impl uDisplay for SocketAddrV4 {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDisplay::fmt(self.ip(), f)?;
+        f.write_str(":")?;
+        <u16 as uDisplay>::fmt(&self.port(), f)
+    }
+}
+
+impl uDisplay for SocketAddrV6 {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("[")?;
+        uDisplay::fmt(self.ip(), f)?;
+        if self.scope_id() != 0 {
+            f.write_str("%")?;
+            <u32 as uDisplay>::fmt(&self.scope_id(), f)?;
+        }
+        f.write_str("]:")?;
+        <u16 as uDisplay>::fmt(&self.port(), f)
+    }
+}
+
+impl uDisplay for SocketAddr {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        match self {
+            SocketAddr::V4(addr) => uDisplay::fmt(addr, f),
+            SocketAddr::V6(addr) => uDisplay::fmt(addr, f),
+        }
+    }
+}
+
+/// Stack buffer for padded addresses, the longest one is `[` + 39 + `%` + 10 + `]:` + 5 bytes
+struct Buf {
+    buf: [u8; 64],
+    len: usize,
+}
+
+impl uWrite for Buf {
+    type Error = ();
+
+    fn write_str(&mut self, s: &str) -> Result<(), ()> {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(())?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+macro_rules! net {
+    ($($type:ty),+) => {
+        $(
+            udisplay_as_udebug!($type);
+
+            impl uDisplayPadded for $type {
+                fn fmt_padded<W>(
+                    &self,
+                    fmt: &mut Formatter<'_, W>,
+                    padding: Padding,
+                    pad_char: char,
+                ) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    let padding = match padding {
+                        Padding::Usual(pad_length) => Padding::LeftAligned(pad_length),
+                        _ => padding,
+                    };
+                    let mut buf = Buf { buf: [0; 64], len: 0 };
+                    if uDisplay::fmt(self, &mut Formatter::new(&mut buf)).is_err() {
+                        return fmt.write_overflow(pad_char, padding);
+                    }
+                    // Safety: buf was written with complete str slices
+                    let s = unsafe { from_utf8_unchecked(buf.buf.get(..buf.len).unwrap_or_default()) };
+                    fmt.write_padded(s, pad_char, padding)
+                }
+            }
+        )+
+    };
+}
+
+net!(
+    Ipv4Addr,
+    Ipv6Addr,
+    IpAddr,
+    SocketAddrV4,
+    SocketAddrV6,
+    SocketAddr
+);
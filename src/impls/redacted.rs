@@ -0,0 +1,87 @@
+use crate::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Hides a secret value from the output
+///
+/// `{}` and `{:?}` write a placeholder instead of the value, which never reaches the writer. The
+/// value is only accessible with [Redacted::expose] or [Redacted::into_inner]. There is no
+/// `PartialEq`, as an early return on the first differing byte would leak the secret through
+/// timing, compare the exposed values with a constant time comparison instead.
+///
+/// ```
+/// use efmt::{uformat, Redacted};
+///
+/// let key = Redacted::new("hunter2");
+/// assert_eq!("***", uformat!(100, "{:?}", key).unwrap().as_str());
+/// let key = Redacted::new("hunter2").placeholder("<key>").with_len();
+/// assert_eq!("<key> (7 bytes)", uformat!(100, "{}", key).unwrap().as_str());
+/// assert_eq!("hunter2", *key.expose());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Redacted<T> {
+    value: T,
+    placeholder: &'static str,
+    len: Option<usize>,
+}
+
+impl<T> Redacted<T> {
+    /// Wraps the value, the placeholder is `***`
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            placeholder: "***",
+            len: None,
+        }
+    }
+
+    /// Sets the placeholder, which is written instead of the value
+    pub fn placeholder(self, placeholder: &'static str) -> Self {
+        Self {
+            placeholder,
+            ..self
+        }
+    }
+
+    /// Returns a reference to the secret value
+    pub fn expose(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the secret value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: AsRef<[u8]>> Redacted<T> {
+    /// Writes the length of the value in bytes behind the placeholder, e.g. `*** (12 bytes)`
+    pub fn with_len(self) -> Self {
+        Self {
+            len: Some(self.value.as_ref().len()),
+            ..self
+        }
+    }
+}
+
+impl<T> uDisplay for Redacted<T> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(self.placeholder)?;
+        if let Some(len) = self.len {
+            f.write_str(" (")?;
+            <usize as uDisplay>::fmt(&len, f)?;
+            f.write_str(" bytes)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> uDebug for Redacted<T> {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        <Self as uDisplay>::fmt(self, f)
+    }
+}
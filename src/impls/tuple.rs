@@ -1,22 +1,73 @@
-use crate::{uDebug, uWrite, Formatter};
+use crate::{uDebug, uDisplay, uWrite, Formatter};
+
+/// Writes the elements of a tuple with `{}`, separated by a separator
+///
+/// ```
+/// use efmt::{uformat, Joined};
+///
+/// let s = uformat!(100, "{}", Joined::new((42, "temp", -7), ";")).unwrap();
+/// assert_eq!("42;temp;-7", s.as_str());
+/// ```
+#[derive(Clone, Copy)]
+pub struct Joined<'a, T> {
+    tuple: T,
+    separator: &'a str,
+}
+
+impl<'a, T> Joined<'a, T> {
+    /// Creates the wrapper, the separator is written between the elements
+    pub fn new(tuple: T, separator: &'a str) -> Self {
+        Self { tuple, separator }
+    }
+}
 
 macro_rules! tuple {
-    ($($T:ident),*; $($i:tt),*) => {
-        impl<$($T,)*> uDebug for ($($T,)*)
+    ($T0:ident $(, $T:ident)*; $i0:tt $(, $i:tt)*) => {
+        impl<$T0, $($T,)*> uDebug for ($T0, $($T,)*)
         where
+            $T0: uDebug,
             $($T: uDebug,)*
         {
             fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
             where
                 W: uWrite + ?Sized,
             {
-                f.debug_tuple("")?$(.field(&self.$i)?)*.finish()
+                f.debug_tuple("")?.field(&self.$i0)?$(.field(&self.$i)?)*.finish()
             }
         }
 
+        impl<$T0, $($T,)*> uDisplay for Joined<'_, ($T0, $($T,)*)>
+        where
+            $T0: uDisplay,
+            $($T: uDisplay,)*
+        {
+            fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: uWrite + ?Sized,
+            {
+                self.tuple.$i0.fmt(f)?;
+                $(
+                    f.write_str(self.separator)?;
+                    self.tuple.$i.fmt(f)?;
+                )*
+                Ok(())
+            }
+        }
     }
 }
 
+// implements the traits for all tuples up to the given length
+macro_rules! tuples {
+    ($($T:ident $i:tt),+) => {
+        tuples!(@ [] $($T $i),+);
+    };
+    (@ [$($U:ident $j:tt),*] $T:ident $i:tt $(, $R:ident $k:tt)*) => {
+        tuple!($($U,)* $T; $($j,)* $i);
+        tuples!(@ [$($U $j,)* $T $i] $($R $k),*);
+    };
+    (@ [$($U:ident $j:tt),*]) => {};
+}
+
 impl uDebug for () {
     fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
     where
@@ -26,15 +77,6 @@ impl uDebug for () {
     }
 }
 
-tuple!(A; 0);
-tuple!(A, B; 0, 1);
-tuple!(A, B, C; 0, 1, 2);
-tuple!(A, B, C, D; 0, 1, 2, 3);
-tuple!(A, B, C, D, E; 0, 1, 2, 3, 4);
-tuple!(A, B, C, D, E, F; 0, 1, 2, 3, 4, 5);
-tuple!(A, B, C, D, E, F, G; 0, 1, 2, 3, 4, 5, 6);
-tuple!(A, B, C, D, E, F, G, H; 0, 1, 2, 3, 4, 5, 6, 7);
-tuple!(A, B, C, D, E, F, G, H, I; 0, 1, 2, 3, 4, 5, 6, 7, 8);
-tuple!(A, B, C, D, E, F, G, H, I, J; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
-tuple!(A, B, C, D, E, F, G, H, I, J, K; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
-tuple!(A, B, C, D, E, F, G, H, I, J, K, L; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+tuples!(
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15
+);
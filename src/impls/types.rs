@@ -0,0 +1,264 @@
+use core::{
+    any::type_name,
+    cell::{Cell, RefCell},
+    cmp::Ordering,
+    marker::PhantomData,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    },
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+    time::Duration,
+};
+
+use crate::{uDebug, uDisplay, uWrite, Convert, Formatter};
+
+// Written like in `core::fmt`, e.g. `1.5s`, `20ms` or `100ns`
+impl uDebug for Duration {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        let secs = self.as_secs();
+        let nanos = self.subsec_nanos();
+        let (int, frac, digits, unit) = if secs > 0 {
+            (secs, nanos, 9, "s")
+        } else if nanos >= 1_000_000 {
+            ((nanos / 1_000_000) as u64, nanos % 1_000_000, 6, "ms")
+        } else if nanos >= 1_000 {
+            ((nanos / 1_000) as u64, nanos % 1_000, 3, "µs")
+        } else {
+            (nanos as u64, 0, 0, "ns")
+        };
+
+        <u64 as uDisplay>::fmt(&int, f)?;
+        if frac > 0 {
+            // the fraction with leading zeros and without trailing zeros
+            let mut frac = frac;
+            let mut digits = digits;
+            while frac % 10 == 0 {
+                frac /= 10;
+                digits -= 1;
+            }
+            let mut conv = Convert::<9>::new(b'0');
+            if conv.u32_pad(frac, digits).is_ok() {
+                f.write_str(".")?;
+                f.write_str(conv.as_str())?;
+            }
+        }
+        f.write_str(unit)
+    }
+}
+
+macro_rules! non_zero {
+    ($($type:ty),+) => {
+        $(
+            impl uDisplay for $type {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    uDisplay::fmt(&self.get(), f)
+                }
+            }
+
+            impl uDebug for $type {
+                #[inline(always)]
+                fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+                where
+                    W: uWrite + ?Sized,
+                {
+                    uDebug::fmt(&self.get(), f)
+                }
+            }
+        )+
+    };
+}
+
+non_zero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize
+);
+
+impl<T> uDisplay for Wrapping<T>
+where
+    T: uDisplay,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> uDebug for Wrapping<T>
+where
+    T: uDebug,
+{
+    #[inline(always)]
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> uDebug for Cell<T>
+where
+    T: uDebug + Copy,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.debug_struct("Cell")?
+            .field("value", &self.get())?
+            .finish()
+    }
+}
+
+impl<T> uDebug for RefCell<T>
+where
+    T: uDebug + ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        // Like `core::fmt`, a mutably borrowed value is not accessible
+        struct BorrowedPlaceholder;
+
+        impl uDebug for BorrowedPlaceholder {
+            fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: uWrite + ?Sized,
+            {
+                f.write_str("<borrowed>")
+            }
+        }
+
+        let mut d = f.debug_struct("RefCell")?;
+        match self.try_borrow() {
+            Ok(value) => d.field("value", &&*value)?,
+            Err(_) => d.field("value", &BorrowedPlaceholder)?,
+        };
+        d.finish()
+    }
+}
+
+impl<T> uDebug for PhantomData<T>
+where
+    T: ?Sized,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("PhantomData<")?;
+        f.write_str(type_name::<T>())?;
+        f.write_str(">")
+    }
+}
+
+impl uDebug for Ordering {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str(match self {
+            Ordering::Less => "Less",
+            Ordering::Equal => "Equal",
+            Ordering::Greater => "Greater",
+        })
+    }
+}
+
+impl<T> uDebug for Range<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start.fmt(f)?;
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<T> uDebug for RangeInclusive<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start().fmt(f)?;
+        f.write_str("..=")?;
+        self.end().fmt(f)
+    }
+}
+
+impl<T> uDebug for RangeFrom<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        self.start.fmt(f)?;
+        f.write_str("..")
+    }
+}
+
+impl<T> uDebug for RangeTo<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..")?;
+        self.end.fmt(f)
+    }
+}
+
+impl<T> uDebug for RangeToInclusive<T>
+where
+    T: uDebug,
+{
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..=")?;
+        self.end.fmt(f)
+    }
+}
+
+impl uDebug for RangeFull {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        f.write_str("..")
+    }
+}
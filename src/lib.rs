@@ -1,20 +1,33 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
-#[cfg(feature = "alloc")]
+#![allow(clippy::result_unit_err)]
+#[cfg(any(feature = "alloc", feature = "std"))]
 extern crate alloc;
 
+mod decimal;
 mod helpers;
 mod impls;
 mod utils;
 use core::{slice::from_raw_parts, str::from_utf8_unchecked};
+pub use impls::{join, HexBytes, Joined, Redacted, Separated};
 
 /// Derive macro
+///
+/// With positional arguments in `#[udisplay(..)]`, `{0}` is the first argument and no longer a
+/// field of a tuple struct, so an index beyond the arguments does not compile:
+///
+/// ```compile_fail
+/// #[derive(efmt::derive::uDisplay)]
+/// #[udisplay("{0}{1}", "#")]
+/// struct Id(u8, u8);
+/// ```
 pub mod derive {
-    pub use efmt_macros::uDebug;
+    pub use efmt_macros::{uDebug, uDisplay};
 }
 
 #[doc(hidden)]
-pub use utils::{uDisplayFloat, uDisplayHex, UnstableDoAsFormatter};
+pub use utils::{uDisplayFloat, uDisplayHex, unstable_check_prefix, UnstableDoAsFormatter};
+pub use utils::{Checked, CheckedError};
 
 /// This trait is used to write a message into a stream.
 #[allow(non_camel_case_types)]
@@ -37,6 +50,15 @@ pub trait uWrite {
         let mut buf: [u8; 4] = [0; 4];
         self.write_str(c.encode_utf8(&mut buf))
     }
+
+    /// Reports a value that could not be converted, see [Overflow::Report]
+    ///
+    /// The error type of most writers cannot express this, so the default implementation does
+    /// nothing and the marker `###` is written instead. Wrap the writer in [Checked] to get a
+    /// distinct error.
+    fn report_overflow(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
 }
 
 /// Creates a `String` using interpolation of runtime expressions.
@@ -134,20 +156,66 @@ macro_rules! udisplay_as_udebug {
 /// | {:08}   | [uDisplayPadded]        | pad_char: '0', padding: Usual(8)             |
 /// | {:<8}   | [uDisplayPadded]        | pad_char: ' ', padding: LeftAligned(8)       |
 /// | {:>8}   | [uDisplayPadded]        | pad_char: ' ', padding: RightAligned(8)      |
-/// | {:^08}  | [uDisplayPadded]        | pad_char: '0', padding: CenterAligned(8)     |
+/// | {:0^8}  | [uDisplayPadded]        | pad_char: '0', padding: CenterAligned(8)     |
 /// | {:8a2}  | [uDisplayFormatted]     | padding: Usual(8), cmd: 'a', behind: 2       |
 /// | {:08a2} | [uDisplayFormatted]     | pad_char: '0', for the rest see above        |
 /// | {:#8a2} | [uDisplayFormatted]     | prefix: true, for the rest see above         |
-/// | {:.2}   | internal float          | padding: Usual(0), behind: 2                 |
-/// | {:8.2}  | internal float          | padding: Usual(8), behind: 2                 |
-/// | {:08.2} | internal float          | pad_char: '0', for the rest see above        |
+/// | {:.2}   | [uDisplayPrecision]     | padding: Usual(0), behind: 2                 |
+/// | {:8.2}  | [uDisplayPrecision]     | padding: Usual(8), behind: 2                 |
+/// | {:08.2} | [uDisplayPrecision]     | pad_char: '0', for the rest see above        |
+/// | {:.5}   | [uDisplayPrecision]     | strings: at most 5 chars, floats see above   |
+/// | {:#8.5} | [uDisplayPrecision]     | with ellipsis `…` if cut, padding: Usual(8)  |
+/// | {:e}    | internal float          | exponential, e.g. `1.2345e3`                 |
+/// | {:.3e}  | internal float          | exponential with 3 decimal places            |
+/// | {:10.2E}| internal float          | padding: Usual(10), upper case `E`           |
+/// | {:r}    | internal float          | shortest representation, which round-trips  |
 /// | {:x}    | internal hex            | padding: Usual(0)                            |
 /// | {:8x}   | internal hex            | pad_char: ' ', padding: Usual(8)             |
 /// | {:08x}  | internal hex            | pad_char: '0', padding: Usual(8)             |
 /// | {:08x}  | internal hex            | pad_char: '0', padding: Usual(8)             |
 /// | {:#x}   | internal hex            | prefix: true                                 |
+/// | {:x?}   | [uDebug]                | integers in hex, e.g. `[1f, 80]`             |
+/// | {:#x?}  | [uDebug]                | pretty, `0x1f`, top-level bytes as hexdump   |
+/// | {:+}    | any of the above        | numbers are written with sign, e.g. `+42`    |
+/// | {:+08.2}| internal float          | sign before the zeros, e.g. `+0003.14`       |
 /// | {{, }}  | -                       | escape braces                                |
 ///
+/// Floats support up to 17 decimal places (`{:.17}`, `{:.17e}`). Without a precision, `{:e}`
+/// prints the shortest digits, which read back to the same number, like `core::fmt`. `{}`, `{:8}`
+/// and `{:+}` write 3 decimal places.
+/// `NaN`, `inf` and `-inf` are written like in `core::fmt`. Negative numbers that are displayed as
+/// zero keep their sign (`-0.000`), the feature `no_negative_zero` writes them as `0.000`.
+/// A value that cannot be converted, e.g. `{:.*}` with a precision above 17, is written as `###`
+/// by default, see [Overflow] for the alternatives.
+///
+/// Byte slices, byte arrays and `heapless::Vec<u8, N>` are written as one contiguous hex number
+/// with `{:x}` (e.g. `01ab00ff`), [HexBytes] adds a separator between the bytes.
+///
+/// Instead of a literal, width and precision can be taken from a `usize` argument at runtime:
+/// `{:1$}` and `{:.1$}` refer to a positional argument, `{:w$}` and `{:.p$}` to a named one and
+/// `{:.*}` takes the precision from the next argument, followed by the value itself.
+///
+/// ```
+/// use efmt::uformat;
+///
+/// let (width, prec) = (8, 2);
+/// let s = uformat!(100, "{:width$.prec$}|{:.*}|{:>4$}", 3.14159, 1, 2.5, 7, 5).unwrap();
+/// assert_eq!("    3.14|2.5|    7", s.as_str());
+/// ```
+///
+/// Arguments are consumed in order by default. As with [`core::write!`], they can also be
+/// selected explicitly by position (`{0}`, `{1:08}`) or by name (`{name}`, `{name:8.2}`).
+/// Named arguments are either passed as `name = expr` or captured from an identifier in scope.
+/// Every argument is evaluated exactly once, no matter how often it is referenced.
+///
+/// ```
+/// use efmt::uformat;
+///
+/// let unit = "mV";
+/// let s = uformat!(100, "{1}/{0} {val:.1}{unit}", 'a', 'b', val = 3.14).unwrap();
+/// assert_eq!("b/a 3.1mV", s.as_str());
+/// ```
+///
 /// For more details see:
 /// - integer formatting: `tests/int.rs`
 /// - float formatting: `tests/float.rs`
@@ -171,6 +239,9 @@ where
     writer: &'w mut W,
     indentation: usize,
     pretty: bool,
+    sign_plus: bool,
+    debug_hex: Option<bool>,
+    overflow: Overflow,
 }
 
 impl<'w, W> Formatter<'w, W>
@@ -183,6 +254,46 @@ where
             writer,
             indentation: 0,
             pretty: false,
+            sign_plus: false,
+            debug_hex: None,
+            overflow: Overflow::Report,
+        }
+    }
+
+    /// Sets the policy for values that cannot be converted
+    ///
+    /// ```
+    /// use efmt::{uwrite, Formatter, Overflow};
+    ///
+    /// let mut s = String::new();
+    /// let mut f = Formatter::new(&mut s);
+    /// f.set_overflow(Overflow::Marker("<ovfl>"));
+    /// uwrite!(f, "{:.*}|", 20, 0.5).unwrap();
+    /// f.set_overflow(Overflow::Saturate);
+    /// uwrite!(f, "{:.*}", 20, 0.5).unwrap();
+    /// assert_eq!("<ovfl>|0.50000000000000000", s);
+    /// ```
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
+    /// Returns the policy for values that cannot be converted
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Handles a value that could not be converted according to the overflow policy
+    ///
+    /// The marker is padded like the value would have been. With [Overflow::Saturate], the caller
+    /// is expected to write the nearest possible representation instead, if there is none,
+    /// the value is reported like with [Overflow::Report].
+    pub fn write_overflow(&mut self, pad_char: char, padding: Padding) -> Result<(), W::Error> {
+        match self.overflow {
+            Overflow::Marker(marker) => self.write_padded(marker, pad_char, padding),
+            Overflow::Saturate | Overflow::Report => {
+                self.writer.report_overflow()?;
+                self.write_padded("###", pad_char, padding)
+            }
         }
     }
 
@@ -210,6 +321,47 @@ where
         Ok(())
     }
 
+    /// Execute the closure with the `+` flag set, numbers are then written with sign
+    pub fn sign_plus(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<(), W::Error>,
+    ) -> Result<(), W::Error> {
+        let sign_plus = self.sign_plus;
+        self.sign_plus = true;
+        f(self)?;
+        self.sign_plus = sign_plus;
+        Ok(())
+    }
+
+    /// Returns whether the `+` flag is set
+    pub fn is_sign_plus(&self) -> bool {
+        self.sign_plus
+    }
+
+    /// Execute the closure with the `x?` (`upper` is false) or `X?` flag set, integers are then
+    /// written in hex by [uDebug]
+    pub fn debug_hex(
+        &mut self,
+        upper: bool,
+        f: impl FnOnce(&mut Self) -> Result<(), W::Error>,
+    ) -> Result<(), W::Error> {
+        let debug_hex = self.debug_hex;
+        self.debug_hex = Some(upper);
+        f(self)?;
+        self.debug_hex = debug_hex;
+        Ok(())
+    }
+
+    /// Returns whether the `x?` flag is set
+    pub fn debug_lower_hex(&self) -> bool {
+        self.debug_hex == Some(false)
+    }
+
+    /// Returns whether the `X?` flag is set
+    pub fn debug_upper_hex(&self) -> bool {
+        self.debug_hex == Some(true)
+    }
+
     /// Write whitespace according to the current `self.indentation`
     fn indent(&mut self) -> Result<(), W::Error> {
         for _ in 0..self.indentation {
@@ -222,45 +374,132 @@ where
     /// Writes a string slice to the underlying buffer and fills it with the pad_char according to
     /// the padding specifications. Here, `Padding::Usual` is treated in the same way as
     /// `Padding::RightAligned`.
+    ///
+    /// The width of `s` is measured in chars, with the feature `display_width` in display
+    /// columns (e.g. 2 for most CJK characters and emoji). Nothing is padded if `s` is wider than
+    /// the pad length.
     pub fn write_padded(
         &mut self,
         s: &str,
         pad_char: char,
         padding: Padding,
     ) -> Result<(), W::Error> {
-        // Converting a char to &str is expensive, so we only do it once
-        let mut buf = [0_u8; 4];
-        let pad_c = pad_char.encode_utf8(&mut buf);
+        self.write_padded_parts(&[s], pad_char, padding)
+    }
+
+    /// Writes at most `max` chars of the string slice, padded like [Formatter::write_padded]. If
+    /// `ellipsis` is set, the last of these chars is replaced by `…` when the string is cut.
+    pub fn write_truncated(
+        &mut self,
+        s: &str,
+        max: usize,
+        ellipsis: bool,
+        pad_char: char,
+        padding: Padding,
+    ) -> Result<(), W::Error> {
+        match s.char_indices().nth(max) {
+            None => self.write_padded(s, pad_char, padding),
+            Some(_) if ellipsis && max > 0 => {
+                let end = s.char_indices().nth(max - 1).map_or(0, |(i, _)| i);
+                // `end` is a char boundary, `get` avoids the panicking branch of indexing
+                self.write_padded_parts(&[s.get(..end).unwrap_or(s), "…"], pad_char, padding)
+            }
+            Some((end, _)) => self.write_padded(s.get(..end).unwrap_or(s), pad_char, padding),
+        }
+    }
+
+    /// Writes a formatted number like [Formatter::write_padded]. If the `+` flag is set, a `+` is
+    /// written before non-negative numbers. With the pad_char `'0'` and `Padding::Usual` (the `0`
+    /// flag), the zeros are inserted after the sign and the `prefix` like in `core::fmt`.
+    pub(crate) fn write_padded_number(
+        &mut self,
+        prefix: &str,
+        s: &str,
+        pad_char: char,
+        padding: Padding,
+    ) -> Result<(), W::Error> {
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => ("-", s),
+            None if self.sign_plus && s != "NaN" => ("+", s),
+            None => ("", s),
+        };
         match padding {
-            Padding::LeftAligned(pad_length) => {
-                self.writer.write_str(s)?;
-                for _ in s.len()..pad_length {
-                    self.writer.write_str(pad_c)?;
+            Padding::Usual(pad_length) if pad_char == '0' => {
+                self.writer.write_str(sign)?;
+                self.writer.write_str(prefix)?;
+                for _ in sign.len() + prefix.len() + s.len()..pad_length {
+                    self.writer.write_str("0")?;
                 }
-                Ok(())
+                self.writer.write_str(s)
             }
+            _ => self.write_padded_parts(&[sign, prefix, s], pad_char, padding),
+        }
+    }
+
+    /// Writes the parts as one string, padded according to the padding specifications
+    fn write_padded_parts(
+        &mut self,
+        parts: &[&str],
+        pad_char: char,
+        padding: Padding,
+    ) -> Result<(), W::Error> {
+        let len = parts.iter().map(|s| width(s)).sum::<usize>();
+        self.write_padded_with(len, pad_char, padding, |f| {
+            for s in parts {
+                f.writer.write_str(s)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Writes the content of the closure, which has the width `len`, padded according to the
+    /// padding specifications. This allows to pad long outputs without buffering them.
+    pub(crate) fn write_padded_with(
+        &mut self,
+        len: usize,
+        pad_char: char,
+        padding: Padding,
+        f: impl FnOnce(&mut Self) -> Result<(), W::Error>,
+    ) -> Result<(), W::Error> {
+        let (before, after) = match padding {
+            Padding::LeftAligned(pad_length) => (0, pad_length.saturating_sub(len)),
             Padding::Usual(pad_length) | Padding::RightAligned(pad_length) => {
-                for _ in s.len()..pad_length {
-                    self.writer.write_str(pad_c)?;
-                }
-                self.writer.write_str(s)
+                (pad_length.saturating_sub(len), 0)
             }
             Padding::CenterAligned(pad_length) => {
-                let padding = pad_length - s.len();
-                let half = padding / 2;
-                for _ in 0..half {
-                    self.writer.write_str(pad_c)?;
-                }
-                self.writer.write_str(s)?;
-                for _ in half..padding {
-                    self.writer.write_str(pad_c)?;
-                }
-                Ok(())
+                let padding = pad_length.saturating_sub(len);
+                (padding / 2, padding - padding / 2)
             }
+        };
+        self.write_repeated(pad_char, before)?;
+        f(self)?;
+        self.write_repeated(pad_char, after)
+    }
+
+    /// Writes the char `n` times
+    fn write_repeated(&mut self, c: char, n: usize) -> Result<(), W::Error> {
+        // Converting a char to &str is expensive, so we only do it once
+        let mut buf = [0_u8; 4];
+        let s = c.encode_utf8(&mut buf);
+        for _ in 0..n {
+            self.writer.write_str(s)?;
         }
+        Ok(())
     }
 }
 
+/// Width of a string for padding, in display columns with the feature `display_width`
+#[cfg(feature = "display_width")]
+fn width(s: &str) -> usize {
+    unicode_width::UnicodeWidthStr::width(s)
+}
+
+/// Width of a string for padding, in chars
+#[cfg(not(feature = "display_width"))]
+fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
 /// Implement this trait if `{}` is to be used with the write macro.
 ///
 /// See [uwrite] for details
@@ -279,6 +518,18 @@ pub trait uDebug {
     fn fmt<W>(&self, _: &mut Formatter<'_, W>) -> Result<(), W::Error>
     where
         W: uWrite + ?Sized;
+
+    /// Formats a slice of values, which is used by the implementations for slices, arrays and
+    /// vectors. By default this is a list like `[1, 2]`, bytes are written as hex dump with
+    /// `{:#x?}`. Only top-level bytes are a hex dump: nested in another value (a struct field, an
+    /// `Option`, a tuple) and without any bytes they are a list like in `core::fmt`.
+    fn fmt_slice<W>(slice: &[Self], f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        Self: Sized,
+        W: uWrite + ?Sized,
+    {
+        f.debug_list()?.entries(slice)?.finish()
+    }
 }
 
 /// This enum determines how the display is to be filled, see [uwrite] for more details.
@@ -294,6 +545,20 @@ pub enum Padding {
     CenterAligned(usize),
 }
 
+/// Policy for values that cannot be converted, e.g. a float with a precision above 17
+///
+/// The policy is set with [Formatter::set_overflow].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Overflow {
+    /// Calls [uWrite::report_overflow] and writes `###`, unless the writer returns an error like
+    /// [Checked] does (default)
+    Report,
+    /// Writes the marker instead of the value, padded like the value
+    Marker(&'static str),
+    /// Writes the nearest possible representation, e.g. with the highest supported precision
+    Saturate,
+}
+
 /// Creating padded output string
 ///
 /// See [uwrite] for details.
@@ -381,16 +646,16 @@ pub trait uDisplayPadded {
 ///                 }
 ///             }
 ///         };
-
+///
 ///         let degs = rad * 180.0 / PI;
 ///         let mins = degs.fract() * 60.0;
-
+///
 ///         let l_min = if decimal_places > 0 {
 ///             decimal_places + 3
 ///         } else {
 ///             decimal_places + 2
 ///         };
-
+///
 ///         let mut conv = Convert::<15>::new(b'0');
 ///         conv.write_u8(sign).unwrap();
 ///         conv.write_u8(b',').unwrap();
@@ -431,6 +696,42 @@ pub trait uDisplayFormatted {
         W: uWrite + ?Sized;
 }
 
+/// Creating output strings with a precision: decimal places of floats, maximum length of strings
+///
+/// See [uwrite] for details. Strings are truncated to `behind` chars, with the `#` flag the cut is
+/// marked with an ellipsis `…`.
+///
+/// ```
+/// use efmt::uformat;
+///
+/// assert_eq!("Temp", uformat!(100, "{:.4}", "Temperature").unwrap().as_str());
+/// assert_eq!("Temp… |", uformat!(100, "{:#6.5}|", "Temperature").unwrap().as_str());
+/// assert_eq!("  3.14", uformat!(100, "{:6.2}", 3.14159).unwrap().as_str());
+/// ```
+#[allow(non_camel_case_types)]
+pub trait uDisplayPrecision {
+    /// Whether the `#` flag is accepted, e.g. for the ellipsis of strings (`{:#.5}`)
+    ///
+    /// Floats do not accept it, so `{:#.2}` on a float does not compile.
+    ///
+    /// ```compile_fail
+    /// let _ = efmt::uformat!(100, "{:#.2}", 1.5);
+    /// ```
+    const PREFIX: bool = true;
+
+    /// Formats the value using the given formatter
+    fn fmt_precision<W>(
+        &self,
+        _: &mut Formatter<'_, W>,
+        prefix: bool,
+        padding: Padding,
+        pad_char: char,
+        behind: usize,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized;
+}
+
 /// Converts numerical data types to &str
 ///
 /// Convert contains a little public toolbox to convert numerical data to strings. So You can
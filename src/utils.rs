@@ -1,4 +1,4 @@
-use crate::{uWrite, Formatter, Padding};
+use crate::{uDisplayPrecision, uWrite, Formatter, Padding};
 
 // Implementation detail of the `uwrite*!` macros
 #[doc(hidden)]
@@ -39,6 +39,22 @@ where
     }
 }
 
+// Implementation detail of the `uwrite*!` macros, rejects the `#` flag with a precision at compile
+// time, if the type does not support it
+#[doc(hidden)]
+#[inline(always)]
+pub fn unstable_check_prefix<T>(_: &T)
+where
+    T: uDisplayPrecision + ?Sized,
+{
+    const {
+        assert!(
+            T::PREFIX,
+            "`#` is not supported with a precision for this type"
+        )
+    }
+}
+
 #[cfg(feature = "heapless07")]
 impl<const N: usize> uWrite for heapless07::String<N> {
     type Error = ();
@@ -93,8 +109,8 @@ impl<const N: usize, L: heapless09::LenType> uWrite for heapless09::Vec<u8, N, L
     }
 }
 
-#[cfg(feature = "std")]
-impl uWrite for String {
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl uWrite for alloc::string::String {
     type Error = ();
 
     fn write_str(&mut self, s: &str) -> Result<(), ()> {
@@ -103,13 +119,40 @@ impl uWrite for String {
     }
 }
 
-#[cfg(feature = "alloc")]
-impl uWrite for alloc::string::String {
-    type Error = ();
+/// Writer adapter, which reports values that cannot be converted as
+/// [CheckedError::Overflow] instead of writing a marker
+///
+/// ```
+/// use efmt::{uwrite, Checked, CheckedError};
+///
+/// let mut w = Checked(String::new());
+/// uwrite!(&mut w, "{:.2}", 1.5).unwrap();
+/// assert_eq!(Err(CheckedError::Overflow), uwrite!(&mut w, "{:.*}", 20, 1.5));
+/// assert_eq!("1.50", w.0);
+/// ```
+pub struct Checked<W>(pub W);
+
+/// Error of the [Checked] writer
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CheckedError<E> {
+    /// The underlying writer failed
+    Write(E),
+    /// A value could not be converted
+    Overflow,
+}
 
-    fn write_str(&mut self, s: &str) -> Result<(), ()> {
-        self.push_str(s);
-        Ok(())
+impl<W> uWrite for Checked<W>
+where
+    W: uWrite,
+{
+    type Error = CheckedError<W::Error>;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+        self.0.write_str(s).map_err(CheckedError::Write)
+    }
+
+    fn report_overflow(&mut self) -> Result<(), Self::Error> {
+        Err(CheckedError::Overflow)
     }
 }
 
@@ -136,6 +179,29 @@ pub trait uDisplayFloat {
     ) -> Result<(), W::Error>
     where
         W: uWrite + ?Sized;
+
+    /// Formats the value in exponential representation, `behind` is `None` if the precision
+    /// was not specified
+    fn fmt_exp<W>(
+        &self,
+        _: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+        behind: Option<usize>,
+        upper: bool,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized;
+
+    /// Formats the value with the shortest representation that reads back to the same value
+    fn fmt_shortest<W>(
+        &self,
+        _: &mut Formatter<'_, W>,
+        padding: Padding,
+        pad_char: char,
+    ) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized;
 }
 
 // This trait is only intended for use within this crate
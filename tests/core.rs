@@ -21,12 +21,111 @@ fn aligned() {
     cmp!("{:>20}", "hello");
 }
 
+#[test]
+fn aligned_multibyte() {
+    for s in ["µA", "°C", "Grüße", "€"] {
+        cmp!("{:8}", s);
+        cmp!("{:<8}", s);
+        cmp!("{:>8}", s);
+        cmp!("{:^8}", s);
+        cmp!("{:_^9}", s);
+        cmp!("{:2}", s);
+        cmp!("{:^1}", s);
+    }
+    for c in ['°', 'µ', '€', '𝄞'] {
+        cmp!("{:3}", c);
+        cmp!("{:>3}", c);
+        cmp!("{:^4}", c);
+        cmp!("{:*<2}", c);
+        cmp!("{:0}", c);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "display_width"))]
+fn aligned_wide() {
+    cmp!("{:6}|", "日本");
+    cmp!("{:^5}|", '😀');
+}
+
+#[test]
+#[cfg(feature = "display_width")]
+fn aligned_wide() {
+    cmp_str!("日本  |", "{:6}|", "日本");
+    cmp_str!(" 😀  |", "{:^5}|", '😀');
+}
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn aligned_heapless() {
+    let s: heapless08::String<8> = heapless08::String::try_from("µA°").unwrap();
+    cmp_str!("µA°     |", "{:8}|", s);
+    cmp_str!("  µA°   |", "{:^8}|", s);
+    cmp_str!("µA°|", "{:>2}|", s);
+}
+
+#[test]
+fn truncated() {
+    for s in ["", "hello", "Grüße", "µA°C"] {
+        cmp!("{:.0}|", s);
+        cmp!("{:.3}|", s);
+        cmp!("{:.5}|", s);
+        cmp!("{:.17}|", s);
+        cmp!("{:.*}|", 20, s);
+        cmp!("{:6.2}|", s);
+        cmp!("{:>6.4}|", s);
+        cmp!("{:_^7.3}|", s);
+        cmp!("{:.*}|", 2, s);
+    }
+    cmp!("{:.0}|{:.1}|{:3.1}|", 'µ', '°', 'c');
+
+    cmp_str!("hel…|", "{:#.4}|", "hello world");
+    cmp_str!("hello|", "{:#.5}|", "hello");
+    cmp_str!("Grü… |", "{:#5.4}|", "Grüße!");
+    cmp_str!("…|", "{:#.1}|", "ab");
+    cmp_str!("|", "{:#.0}|", "ab");
+}
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn truncated_heapless() {
+    let s: heapless08::String<16> = heapless08::String::try_from("Grüße, Welt").unwrap();
+    cmp_str!("Grüße|", "{:.5}|", s);
+    cmp_str!(" Grüß…|", "{:>#6.5}|", s);
+}
+
 #[test]
 fn fmt() {
     cmp!("Hello, world!");
     cmp!("The answer is {}", 42);
 }
 
+#[test]
+#[allow(clippy::approx_constant)]
+fn arguments() {
+    cmp!("{1} {0} {1}", "a", "b");
+    cmp!("{} {0} {}", 1, 2);
+    cmp!("{x} {y:?} {x:>4}", x = 1, y = Some(2));
+    cmp!("{0:08.2} {name:^#10x}", 3.14, name = 255);
+
+    let temp = 21.5;
+    let unit = "°C";
+    cmp!("{temp:.1}{unit}");
+    cmp!("{0:.1}{unit} {0:6.2}", temp);
+}
+
+#[test]
+fn arguments_evaluated_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+    cmp_str!("1 1 1", "{0} {0} {v}", next(), v = 1);
+    cmp_str!("2, 2", "{x}, {x}", x = next());
+    assert_eq!(calls, 2);
+}
+
 #[test]
 #[cfg(not(feature = "std"))]
 fn uwriteln() {
@@ -62,3 +161,99 @@ fn result() {
     cmp!("{:?}", Err::<(), _>(2));
     cmp!("{:#?}", Err::<(), _>(2));
 }
+
+#[test]
+fn net() {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    let v4 = Ipv4Addr::new(192, 168, 0, 1);
+    cmp!("{}", v4);
+    cmp!("{:?}", v4);
+    cmp!("{}", Ipv4Addr::UNSPECIFIED);
+    cmp!("{}", Ipv4Addr::BROADCAST);
+    cmp!("{:18}|", v4);
+    cmp!("{:>18}|", v4);
+    cmp!("{:*^18}|", v4);
+
+    for v6 in [
+        Ipv6Addr::UNSPECIFIED,
+        Ipv6Addr::LOCALHOST,
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1),
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 1),
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 1, 1, 1, 1),
+        Ipv6Addr::new(0x2001, 0, 1, 1, 1, 1, 1, 0),
+        Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0xc000, 0x280),
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0),
+        Ipv6Addr::new(
+            0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff,
+        ),
+        Ipv4Addr::new(192, 0, 2, 128).to_ipv6_mapped(),
+    ] {
+        cmp!("{}", v6);
+        cmp!("{:?}", v6);
+        cmp!("{:<42}|", v6);
+        cmp!("{}", IpAddr::V6(v6));
+        cmp!("{}", SocketAddr::V6(SocketAddrV6::new(v6, 8080, 0, 0)));
+        cmp!("{:?}", SocketAddrV6::new(v6, 443, 0, 3));
+        cmp!("{:60}|", SocketAddrV6::new(v6, 65535, 0, u32::MAX));
+    }
+    cmp_str!(
+        "::ffff:192.0.2.128",
+        "{}",
+        Ipv4Addr::new(192, 0, 2, 128).to_ipv6_mapped()
+    );
+
+    cmp!("{}", IpAddr::V4(v4));
+    cmp!("{}", SocketAddrV4::new(v4, 80));
+    cmp!("{:?}", SocketAddr::V4(SocketAddrV4::new(v4, 80)));
+    cmp!("{:>24}|", SocketAddr::V4(SocketAddrV4::new(v4, 80)));
+}
+
+#[test]
+fn joined() {
+    use efmt::Joined;
+
+    cmp_str!("1", "{}", Joined::new((1,), ", "));
+    cmp_str!("1, -2, x", "{}", Joined::new((1, -2, 'x'), ", "));
+    cmp_str!(
+        "12:00;sensor;-3;true|",
+        "{}|",
+        Joined::new(("12:00", "sensor", -3_i8, true), ";")
+    );
+    let t = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    cmp_str!(
+        "1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16",
+        "{}",
+        Joined::new(t, ",")
+    );
+    let name = "efmt";
+    cmp_str!("efmt 42", "{}", Joined::new((&name, &42_u64), " "));
+}
+
+#[test]
+fn join() {
+    use efmt::join;
+
+    let values = [1, -20, 300];
+    cmp_str!("", "{}", join(&[0_u8; 0], ", "));
+    cmp_str!("1, -20, 300", "{}", join(&values, ", "));
+    cmp_str!("1, -20, 300", "{:?}", join(values, ", "));
+    cmp_str!("0001;-020;0300", "{:04}", join(&values[..], ";"));
+    cmp_str!("1    |-20  |300  ", "{:<5}", join(values.iter(), "|"));
+    cmp_str!("0x00ff 0x1000", "{:#06x}", join([255_u16, 4096], " "));
+    cmp_str!("1.50/-0.25", "{:.2}", join([1.5_f32, -0.25], "/"));
+    cmp_str!("\"a\", \"b\"", "{:?}", join(["a", "b"], ", "));
+    cmp_str!("a-b-c", "{}", join(["a", "b", "c"], "-"));
+    cmp_str!("[ab, cd]", "[{:.2}]", join(["abc", "cde"], ", "));
+    cmp_str!("0 2 4", "{}", join((0..5).filter(|i| i % 2 == 0), " "));
+}
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn join_heapless() {
+    use efmt::join;
+
+    let v: heapless08::Vec<u32, 4> = heapless08::Vec::from_slice(&[7, 8, 9]).unwrap();
+    cmp_str!("07,08,09", "{:02}", join(&v, ","));
+}
@@ -76,6 +76,12 @@ fn tuples() {
     cmp!("{:?}", (1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
     cmp!("{:?}", (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11));
     cmp!("{:?}", (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+    // core::fmt stops at 12 elements
+    cmp_str!(
+        "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)",
+        "{:?}",
+        (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)
+    );
 
     cmp!("{:#?}", ());
     cmp!("{:#?}", (1,));
@@ -91,6 +97,95 @@ fn slice() {
     cmp!("{:#?}", [0; 0]);
     cmp!("{:#?}", [0]);
     cmp!("{:#?}", [0, 1]);
+
+    cmp!("{:?}", [7_u8; 33]);
+    cmp!("{:?}", [-1_i16; 512]);
+    cmp!("{:#?}", [[1_u8; 40]; 2]);
+}
+
+#[test]
+fn unsized_slice() {
+    let v = [1_u8, 2, 3];
+    cmp!("{:?}", &v[..]);
+    cmp!("{:?}", &v[..0]);
+    cmp!("{:#?}", &v[1..]);
+    cmp!("{:?}", &[Some(-1_i64), None][..]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn alloc() {
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+    cmp!("{:?}", vec![1, 2, 3]);
+    cmp!("{:#?}", vec![vec![1_i8, -1], vec![]]);
+    cmp!("{:?}", Vec::<u8>::new());
+    cmp!("{:?}", Box::new(42));
+    cmp!("{:?}", Box::new([1, 2]) as Box<[i32]>);
+    cmp!("{:?}", Rc::new(Some(7_u16)));
+    cmp!("{:?}", Arc::new((1, 2)));
+    cmp!("{:?}", Cow::Borrowed(&[1, 2][..]));
+
+    cmp!("{}", String::from("hello"));
+    cmp!("{:>8}|{:.2}", String::from("µA"), String::from("hello"));
+    cmp!(
+        "{} {}",
+        Cow::Borrowed("borrowed"),
+        Cow::<str>::Owned("owned".into())
+    );
+    cmp!("{:^9}", Cow::Borrowed("°C"));
+    cmp!(
+        "{:.3}|{:>6.2}",
+        Cow::Borrowed("hello"),
+        Cow::<str>::Owned("µAmps".into())
+    );
+    cmp!("{} {}", Box::new(-15_i8), Rc::new('x'));
+    cmp!("{}", Arc::<str>::from("shared"));
+}
+
+#[test]
+fn core_types() {
+    use core::{
+        cell::{Cell, RefCell},
+        marker::PhantomData,
+        num::{NonZeroI64, NonZeroU16, NonZeroU8, Wrapping},
+        time::Duration,
+    };
+
+    for d in [
+        Duration::ZERO,
+        Duration::from_secs(3),
+        Duration::from_millis(1500),
+        Duration::from_millis(20),
+        Duration::from_micros(1250),
+        Duration::from_micros(7),
+        Duration::from_nanos(100),
+        Duration::from_nanos(1_000_000_001),
+        Duration::from_nanos(1_001),
+        Duration::new(u64::MAX, 999_999_999),
+    ] {
+        cmp!("{:?}", d);
+    }
+
+    cmp!("{:?} {}", NonZeroU8::new(7).unwrap(), NonZeroU8::MAX);
+    cmp!("{:?} {}", NonZeroU16::MIN, NonZeroI64::new(-5).unwrap());
+    cmp!("{:?} {}", Wrapping(42_u32), Wrapping(-1_i8));
+
+    cmp!("{:?}", Cell::new(5_u8));
+    cmp!("{:#?}", Cell::new(Some(1)));
+    let cell = RefCell::new([1, 2]);
+    cmp!("{:?}", cell);
+    let borrowed = cell.borrow_mut();
+    cmp!("{:?}", cell);
+    drop(borrowed);
+
+    cmp!("{:?}", PhantomData::<u8>);
+    cmp!("{:?}", PhantomData::<[u16]>);
+    cmp!("{:?}", [1.cmp(&2), 2.cmp(&2), 3.cmp(&2)]);
+
+    cmp!("{:?} {:?} {:?}", 1..5, 1..=5, 3_u8..);
+    cmp!("{:?} {:?} {:?}", ..5, ..=-5, ..);
+    cmp!("{:#?}", (0..2, 10_u8..=20));
 }
 
 #[test]
@@ -126,3 +221,329 @@ fn generic() {
 
     cmp!("{:?}", Y::Z(0));
 }
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn heapless() {
+    use heapless08::{
+        binary_heap::Max, BinaryHeap, Deque, FnvIndexMap, FnvIndexSet, HistoryBuffer, LinearMap,
+        Vec,
+    };
+
+    #[derive(Debug, uDebug)]
+    struct State {
+        pending: Vec<u16, 4>,
+        queue: Deque<i8, 4>,
+        flags: LinearMap<u8, bool, 4>,
+    }
+
+    let mut state = State {
+        pending: Vec::from_slice(&[1, 2]).unwrap(),
+        queue: Deque::new(),
+        flags: LinearMap::new(),
+    };
+    cmp!("{:?}", state);
+    cmp!("{:#?}", state);
+    state.queue.push_back(-1).unwrap();
+    state.queue.push_front(7).unwrap();
+    state.flags.insert(1, true).unwrap();
+    state.flags.insert(2, false).unwrap();
+    cmp!("{:?}", state);
+    cmp!("{:#?}", state);
+
+    let mut map = FnvIndexMap::<u8, Vec<u8, 2>, 4>::new();
+    cmp!("{:?}", map);
+    cmp!("{:#?}", map);
+    map.insert(3, Vec::from_slice(&[]).unwrap()).unwrap();
+    map.insert(1, Vec::from_slice(&[4, 5]).unwrap()).unwrap();
+    cmp!("{:?}", map);
+    cmp!("{:#?}", map);
+
+    let mut set = FnvIndexSet::<i32, 4>::new();
+    cmp!("{:?}", set);
+    cmp!("{:#?}", set);
+    set.insert(-3).unwrap();
+    set.insert(8).unwrap();
+    cmp!("{:?}", set);
+    cmp!("{:#?}", [&set, &set]);
+
+    let mut heap = BinaryHeap::<u32, Max, 4>::new();
+    heap.push(1).unwrap();
+    heap.push(9).unwrap();
+    heap.push(4).unwrap();
+    cmp!("{:?}", heap);
+
+    let mut history = HistoryBuffer::<u8, 3>::new();
+    cmp!("{:?}", history);
+    history.extend([1, 2, 3, 4]);
+    cmp!("{:?}", history);
+    cmp!("{:#?}", history);
+}
+
+#[test]
+fn escaped() {
+    for s in [
+        "",
+        "plain",
+        "\"quoted\" and 'single'",
+        "tab\tcr\rlf\n\0end\\",
+        "\u{7f}\u{1b}[0m",
+        "µs, 温度, 🦀",
+        "\u{1}\u{9f}",
+    ] {
+        cmp!("{:?}", s);
+        cmp!("{:#?}", [s]);
+        for c in s.chars() {
+            cmp!("{:?}", c);
+        }
+    }
+
+    // only control chars are escaped as `\u{..}`, other non-printable chars and combining marks
+    // are written as they are
+    cmp_str!("\"e\u{301}\u{301}\"", "{:?}", "e\u{301}\u{301}");
+    cmp_str!("\"\u{301}start\"", "{:?}", "\u{301}start");
+    cmp_str!("'\u{301}'", "{:?}", '\u{301}');
+    cmp_str!("\"zero\u{200b}width\"", "{:?}", "zero\u{200b}width");
+    cmp_str!("'\u{10ffff}'", "{:?}", '\u{10ffff}');
+    cmp!("{:?}", '\'');
+    cmp!("{:?}", '"');
+
+    #[derive(Debug, uDebug)]
+    struct Named<'a> {
+        name: &'a str,
+        sep: char,
+    }
+    cmp!(
+        "{:?}",
+        Named {
+            name: "a\"b",
+            sep: '\n'
+        }
+    );
+    cmp!(
+        "{:#?}",
+        Some(Named {
+            name: "x",
+            sep: '\''
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn escaped_heapless() {
+    let s: heapless08::String<16> = heapless08::String::try_from("say \"hi\"\n").unwrap();
+    cmp!("{:?}", s);
+    cmp!("{}", s);
+}
+
+#[test]
+fn attributes() {
+    use core::marker::PhantomData;
+
+    fn celsius<W: uWrite + ?Sized>(value: &i16, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(f, "{}.{} °C", value / 10, value % 10)
+    }
+
+    #[derive(uDebug)]
+    struct NoDebug;
+
+    #[derive(uDebug)]
+    #[udebug(bound = "")]
+    struct Regs<T> {
+        #[udebug(hex)]
+        ctrl: u8,
+        #[udebug(hex, rename = "STATUS")]
+        status: u16,
+        #[udebug(with = celsius)]
+        temp: i16,
+        #[udebug(skip)]
+        _marker: PhantomData<T>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(uDebug)]
+    struct Pair(#[udebug(skip)] u8, #[udebug(with = celsius)] i16, bool);
+
+    #[derive(uDebug)]
+    #[udebug(transparent)]
+    struct Id(u32);
+
+    #[derive(uDebug)]
+    #[udebug(transparent)]
+    struct Meters {
+        #[udebug(hex)]
+        value: u32,
+        #[udebug(skip)]
+        _unit: (),
+    }
+
+    #[allow(dead_code)]
+    #[derive(uDebug)]
+    enum Event {
+        #[udebug(rename = "start")]
+        Start,
+        Data(#[udebug(hex)] u8, #[udebug(skip)] u8),
+        Error {
+            #[udebug(rename = "errno")]
+            code: i32,
+            #[udebug(skip)]
+            _detail: u8,
+        },
+    }
+
+    let regs = Regs::<NoDebug> {
+        ctrl: 0x1f,
+        status: 0x8000,
+        temp: 215,
+        _marker: PhantomData,
+    };
+    cmp_str!(
+        "Regs { ctrl: 0x1f, STATUS: 0x8000, temp: 21.5 °C }",
+        "{:?}",
+        regs
+    );
+    cmp_str!(
+        "Regs {\n    ctrl: 0x1f,\n    STATUS: 0x8000,\n    temp: 21.5 °C,\n}",
+        "{:#?}",
+        regs
+    );
+    cmp_str!("Pair(2.0 °C, true)", "{:?}", Pair(1, 20, true));
+    cmp_str!("Some(7)", "{:?}", Some(Id(7)));
+    cmp_str!(
+        "0xff",
+        "{:?}",
+        Meters {
+            value: 255,
+            _unit: ()
+        }
+    );
+    cmp_str!("start", "{:?}", Event::Start);
+    cmp_str!("Data(0xa)", "{:?}", Event::Data(10, 1));
+    cmp_str!(
+        "[Error { errno: -2 }]",
+        "{:?}",
+        [Event::Error {
+            code: -2,
+            _detail: 0
+        }]
+    );
+}
+
+#[test]
+fn redact() {
+    use efmt::Redacted;
+
+    // collects everything, which reaches the writer
+    struct Recorder(String);
+
+    impl uWrite for Recorder {
+        type Error = ();
+
+        fn write_str(&mut self, s: &str) -> Result<(), ()> {
+            assert!(!s.contains("hunter2") && !s.contains("c0ffee"));
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[derive(uDebug)]
+    struct Config<'a> {
+        ssid: &'a str,
+        #[udebug(redact)]
+        password: &'a str,
+        #[udebug(redact = "<key>", len)]
+        key: [u8; 3],
+        token: Redacted<&'a str>,
+    }
+
+    #[derive(uDebug)]
+    enum Auth<'a> {
+        Psk(#[udebug(redact = "<psk>")] &'a str),
+        Key {
+            #[udebug(redact, len)]
+            key: &'a [u8],
+        },
+    }
+
+    let config = Config {
+        ssid: "home",
+        password: "hunter2",
+        key: [0xc0, 0xff, 0xee],
+        token: Redacted::new("hunter2").with_len(),
+    };
+    let mut rec = Recorder(String::new());
+    uwrite!(rec, "{:?}", config).unwrap();
+    assert_eq!(
+        rec.0,
+        "Config { ssid: \"home\", password: ***, key: <key> (3 bytes), token: *** (7 bytes) }"
+    );
+
+    let mut rec = Recorder(String::new());
+    uwrite!(rec, "{:#?}", config).unwrap();
+    assert_eq!(
+        rec.0,
+        "Config {\n    ssid: \"home\",\n    password: ***,\n    key: <key> (3 bytes),\n    \
+         token: *** (7 bytes),\n}"
+    );
+
+    let mut rec = Recorder(String::new());
+    uwrite!(
+        rec,
+        "{:?} {:?}",
+        Auth::Psk("hunter2"),
+        Auth::Key { key: b"c0ffee" }
+    )
+    .unwrap();
+    assert_eq!(rec.0, "Psk(<psk>) Key { key: *** (6 bytes) }");
+
+    let secret = Redacted::new(*b"hunter2").placeholder("[hidden]");
+    let mut rec = Recorder(String::new());
+    uwrite!(
+        rec,
+        "{} {:?} {:#?}",
+        secret,
+        secret,
+        Some(secret.with_len())
+    )
+    .unwrap();
+    assert_eq!(rec.0, "[hidden] [hidden] Some(\n    [hidden] (7 bytes),\n)");
+    assert_eq!(b"hunter2", secret.expose());
+    assert_eq!(*b"hunter2", secret.into_inner());
+}
+
+#[test]
+fn debug_hex() {
+    #[derive(Debug, uDebug)]
+    struct Regs {
+        ctrl: u8,
+        status: u16,
+        offset: i32,
+        flags: [u32; 2],
+        mode: Option<usize>,
+        bits: (i8, u64, i128),
+        mac: [u8; 6],
+    }
+
+    let regs = Regs {
+        ctrl: 0x1f,
+        status: 0x80,
+        offset: -2,
+        flags: [0xdead_beef, 0],
+        mode: Some(10),
+        bits: (-128, u64::MAX, i128::MIN),
+        mac: [0x02, 0x00, 0x5e, 0x10, 0xab, 0xff],
+    };
+    cmp!("{:x?}", regs);
+    cmp!("{:X?}", regs);
+    cmp!("{:#x?}", regs);
+    cmp!("{:#X?}", regs);
+    cmp!("{:?}", regs);
+
+    cmp!("{:x?}", 255_u8);
+    cmp!("{:#X?}", 255_u8);
+    cmp!("{:x?}", [0x1f_u8, 0x80]);
+    cmp!("{:#x?}", Some(&regs.mac[..2]));
+    cmp!("{:x?} {:?} {:X?}", 42_i16, 42_i16, -1_isize);
+    cmp!("{:x?}", (1_u32, true, 'x', "text"));
+}
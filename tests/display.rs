@@ -0,0 +1,115 @@
+mod macros;
+
+use efmt::{derive::uDisplay, uDisplay};
+
+#[test]
+fn struct_() {
+    #[derive(uDisplay)]
+    #[udisplay("{name}: {temp:08.2} °C")]
+    struct Sensor<'a> {
+        name: &'a str,
+        temp: f32,
+    }
+
+    #[derive(uDisplay)]
+    #[udisplay("{0:#06x}/{1}")]
+    struct Reg(u16, bool);
+
+    #[derive(uDisplay)]
+    #[udisplay("[{0}]")]
+    struct Wrapper<T>(T);
+
+    #[derive(uDisplay)]
+    struct Unit;
+
+    cmp_str!(
+        "boiler: 00072.50 °C",
+        "{}",
+        Sensor {
+            name: "boiler",
+            temp: 72.5
+        }
+    );
+    cmp_str!("0x001f/true", "{}", Reg(0x1f, true));
+    cmp_str!("[[0x001f/false]]", "{}", Wrapper(Wrapper(Reg(0x1f, false))));
+    cmp_str!("Unit", "{}", Unit);
+}
+
+#[test]
+fn args() {
+    const UNIT: &str = "V";
+
+    #[derive(uDisplay)]
+    #[udisplay("{value:.1}{UNIT} ({count} of {max})", max = self.limit * 2)]
+    struct Voltage {
+        value: f32,
+        count: u8,
+        limit: u8,
+    }
+
+    // positional arguments take precedence over the fields of a tuple struct
+    #[derive(uDisplay)]
+    #[udisplay("{:>5}|{name:?}|{0}", self.0.len(), name = self.0)]
+    struct Name<'a>(&'a str);
+
+    cmp_str!(
+        "3.3V (2 of 10)",
+        "{}",
+        Voltage {
+            value: 3.3,
+            count: 2,
+            limit: 5
+        }
+    );
+    cmp_str!("    4|\"efmt\"|4", "{}", Name("efmt"));
+}
+
+#[test]
+fn enum_() {
+    #[derive(uDisplay)]
+    enum Mode {
+        Idle,
+        #[udisplay("running")]
+        Running,
+        #[udisplay("error {code:+} at {line}")]
+        Error {
+            code: i16,
+            line: u32,
+        },
+        #[udisplay("{0:e} {1}")]
+        Value(f64, char),
+        #[udisplay("unused")]
+        Unused(u8),
+        #[udisplay("freq {f} Hz")]
+        Freq {
+            f: u32,
+        },
+    }
+
+    cmp_str!("Idle", "{}", Mode::Idle);
+    cmp_str!("running", "{}", Mode::Running);
+    cmp_str!("error -7 at 42", "{}", Mode::Error { code: -7, line: 42 });
+    cmp_str!("error +7 at 42", "{}", Mode::Error { code: 7, line: 42 });
+    cmp_str!("1.5e3 x", "{}", Mode::Value(1500.0, 'x'));
+    cmp_str!("unused", "{}", Mode::Unused(1));
+    cmp_str!("freq 440 Hz", "{}", Mode::Freq { f: 440 });
+    cmp_str!("Idle, running", "{}, {}", Mode::Idle, Mode::Running);
+}
+
+#[test]
+fn generic() {
+    #[derive(uDisplay)]
+    enum Either<L, R> {
+        #[udisplay("L={0}")]
+        Left(L),
+        #[udisplay("R={0}")]
+        Right(R),
+    }
+
+    fn check<T: uDisplay>(expected: &str, value: T) {
+        cmp_str!(expected, "<{}>", value);
+    }
+
+    check("<L=1>", Either::<u8, &str>::Left(1));
+    check("<R=x>", Either::<u8, &str>::Right("x"));
+}
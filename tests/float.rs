@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant, clippy::excessive_precision)]
+
 mod macros;
 
 #[test]
@@ -43,14 +45,30 @@ fn f32() {
     cmp!("{:.3}", f32::MIN_POSITIVE);
     cmp!("{:.6}", 8388607.1234567_f32);
 
-    cmp_str!("ovfl", "{:.3}", F_MAX32 + 1.0_f32); // std::format "8388609.000"
-    cmp_str!("-ovfl", "{:.3}", -F_MAX32 - 1.0_f32); // std::format "-8388609.000"
-    cmp_str!("ovfl", "{:.3}", f32::INFINITY); // std::format "inf"
-    cmp_str!("-ovfl", "{:.3}", f32::NEG_INFINITY); // std::format "-inf"
+    cmp!("{:.3}", F_MAX32 + 1.0_f32);
+    cmp!("{:.3}", -F_MAX32 - 1.0_f32);
+    cmp!("{:.3}", f32::INFINITY);
+    cmp!("{:.3}", f32::NEG_INFINITY);
     cmp_str!("321.123", "{}", 321.123456_f32); // std::format "321.12344"
     cmp_str!("321.000", "{}", 321.0_f32); // std::format "321"
 }
 
+#[test]
+fn runtime_precision() {
+    for prec in 0..7 {
+        cmp!("{:.*}", prec, 3.14159_f32);
+        cmp!("{:.*}", prec, -2.71828_f64);
+        cmp!("{:.1$}", 1.10555_f64, prec);
+        cmp!("{:>10.prec$}", 0.70555_f32);
+    }
+
+    for width in [0, 4, 12] {
+        cmp!("{:w$.p$}", 3.14_f64, w = width, p = 2);
+        cmp!("{:0^w$.p$}", -3.14_f32, w = width, p = 1);
+        cmp!("{2:<0$.1$}", width, 3, 1.5_f64);
+    }
+}
+
 #[test]
 fn f64() {
     cmp!("{:10.3}", 3.14_f64);
@@ -94,10 +112,391 @@ fn f64() {
     cmp!("{:.3}", f64::EPSILON);
     cmp!("{:.3}", f64::MIN_POSITIVE);
 
-    cmp_str!("ovfl", "{:.3}", F_MAX64 + 1.0_f64); // std::format "1844674407371.000"
-    cmp_str!("-ovfl", "{:.3}", -F_MAX64 - 1.0_f64); // std::format "-1844674407371.000"
-    cmp_str!("ovfl", "{:.3}", f64::INFINITY); // std::format "inf"
-    cmp_str!("-ovfl", "{:.3}", f64::NEG_INFINITY); // std::format "-inf"
+    cmp!("{:.3}", F_MAX64 + 1.0_f64);
+    cmp!("{:.3}", -F_MAX64 - 1.0_f64);
+    cmp!("{:.3}", f64::INFINITY);
+    cmp!("{:.3}", f64::NEG_INFINITY);
     cmp_str!("321.123", "{}", 321.123456_f64); // std::format "321.123456"
     cmp_str!("321.000", "{}", 321.0_f64); // std::format "321"
 }
+
+#[test]
+fn special_values() {
+    for f in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        cmp!("{}", f);
+        cmp!("{:?}", f);
+        cmp!("{:.0}", f);
+        cmp!("{:.9}", f);
+        cmp!("{:8.2}", f);
+        cmp!("{:<8.2}", f);
+        cmp!("{:^8.2}", f);
+        cmp!("{:_>8.2}", f);
+        cmp!("{:e}", f);
+        cmp!("{:10.3E}", f);
+        cmp!("{:.2}", f as f32);
+        cmp!("{:<8.2}", f as f32);
+        cmp!("{:^9e}", f as f32);
+    }
+    cmp!("{:08.2}", f64::INFINITY);
+    cmp!("{:08.2}", f32::NAN);
+    cmp!("{:.1}", 1e300_f64 * 10.0);
+
+    let mut conv = efmt::Convert::<10>::new(b' ');
+    conv.f32_pad(f32::NEG_INFINITY, 6, 2).unwrap();
+    assert_eq!("  -inf", conv.as_str());
+    let mut conv = efmt::Convert::<10>::new(b' ');
+    conv.f64_pad(f64::NAN, 6, 2).unwrap();
+    assert_eq!("   NaN", conv.as_str());
+    let mut conv = efmt::Convert::<10>::new(b' ');
+    conv.f64_pad(-123.25, 3, 2).unwrap();
+    assert_eq!("-123.25", conv.as_str());
+}
+
+#[test]
+fn zero_padding() {
+    for f in [
+        0.0,
+        3.14159,
+        -3.14159,
+        -1234.5,
+        1e20,
+        -0.5,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        cmp!("{:08.2}", f);
+        cmp!("{:030.17}", f);
+        cmp!("{:03.1}", f);
+        cmp!("{:<08.2}", f);
+        cmp!("{:^09.3}", f);
+        cmp!("{:0>8.2}", f);
+        cmp!("{:0<8.2}", f);
+        cmp!("{:012e}", f);
+        cmp!("{:<012.2E}", f);
+        cmp!("{:08.2}", f as f32);
+        cmp!("{:012.3e}", f as f32);
+    }
+    cmp!("{:08.2}", f64::NAN);
+    cmp_str!("-001.5", "{:06r}", -1.5_f64);
+    cmp_str!("-1.5000", "{:0<7r}", -1.5_f32);
+}
+
+#[test]
+fn sign_plus() {
+    for f in [
+        0.0,
+        3.14159,
+        -3.14159,
+        1234.5,
+        -0.375,
+        1e20,
+        f64::INFINITY,
+        f64::NAN,
+    ] {
+        cmp!("{:+.2}", f);
+        cmp!("{:+10.2}", f);
+        cmp!("{:<+10.3}", f);
+        cmp!("{:^+11.1}", f);
+        cmp!("{:+e}", f);
+        cmp!("{:+12.3E}", f);
+        cmp!("{:+.1}", f as f32);
+        cmp!("{:+010.2}", f);
+        cmp!("{:+09.3}", f as f32);
+    }
+    cmp_str!("+1.500", "{:+}", 1.5);
+    #[cfg(not(feature = "no_negative_zero"))]
+    cmp_str!("-0.000", "{:+}", -0.0);
+    cmp_str!("  +2.500", "{:+8}", 2.5_f32);
+    cmp_str!("-002.500", "{:+08}", -2.5);
+    cmp_str!("0.100   ", "{:<8}", 0.1_f32);
+
+    // the flag and the width do not change the digits of `{}`
+    for f in [3.14, -0.375, 1234.5, 1e20, f64::NAN] {
+        let display = efmt::uformat!(100, "{}", f).unwrap();
+        let sign = if f.is_sign_negative() || f.is_nan() {
+            ""
+        } else {
+            "+"
+        };
+        cmp_str!(format!("{}{}", sign, display).as_str(), "{:+}", f);
+        cmp_str!(format!("{:>30}", display).as_str(), "{:30}", f);
+        let display = efmt::uformat!(100, "{}", f as f32).unwrap();
+        cmp_str!(format!("{:<12}", display).as_str(), "{:<12}", f as f32);
+    }
+    cmp_str!("+1.5", "{:+r}", 1.5_f64);
+    cmp_str!("   +1.5", "{:+7r}", 1.5_f64);
+}
+
+#[test]
+#[cfg(not(feature = "no_negative_zero"))]
+fn negative_zero() {
+    cmp!("{:.3}", -0.0_f32);
+    cmp!("{:.3}", -0.0_f64);
+    cmp!("{:.3}", -0.0001_f32);
+    cmp!("{:.0}", -0.4_f64);
+    cmp!("{:8.1}", -0.04_f64);
+    cmp!("{:.12}", -1e-15_f64);
+    cmp!("{:.2}", -1e-300_f64);
+    cmp!("{:.2e}", -0.0_f32);
+    cmp_str!("-0.000", "{}", -0.0_f64); // std::format "-0"
+    cmp_str!("-0", "{:r}", -0.0_f64);
+
+    let mut conv = efmt::Convert::<10>::new(b' ');
+    conv.f32_pad(-0.0, 6, 2).unwrap();
+    assert_eq!(" -0.00", conv.as_str());
+}
+
+#[test]
+#[cfg(feature = "no_negative_zero")]
+fn negative_zero() {
+    cmp_str!("0.000", "{:.3}", -0.0_f32);
+    cmp_str!("0.000", "{:.3}", -0.0_f64);
+    cmp_str!("0.000", "{:.3}", -0.0001_f32);
+    cmp_str!("0", "{:.0}", -0.4_f64);
+    cmp_str!("     0.0", "{:8.1}", -0.04_f64);
+    cmp_str!("0.000000000000", "{:.12}", -1e-15_f64);
+    cmp_str!("0.00", "{:.2}", -1e-300_f64);
+    cmp_str!("0e0", "{:e}", -0.0_f64);
+    cmp_str!("0.00e0", "{:.2e}", -0.0_f32);
+    cmp_str!("0.000", "{}", -0.0_f64);
+    cmp_str!("0", "{:r}", -0.0_f64);
+    cmp_str!("-0.1", "{:.1}", -0.05_f64); // rounds away from zero
+    cmp!("{:.3}", -0.0005_f64);
+
+    let mut conv = efmt::Convert::<10>::new(b' ');
+    conv.f32_pad(-0.0, 6, 2).unwrap();
+    assert_eq!("  0.00", conv.as_str());
+}
+
+#[test]
+fn overflow() {
+    use efmt::{uwrite, Checked, CheckedError, Formatter, Overflow};
+
+    cmp_str!("###", "{:.*}", 18, 1.5_f32);
+    cmp_str!("  ###", "{:5.*}", 18, 1.5_f64);
+    cmp_str!("###  ", "{:<5.*e}", 20, 1.5_f64);
+
+    let mut s = String::new();
+    let mut f = Formatter::new(&mut s);
+    f.set_overflow(Overflow::Marker("-"));
+    uwrite!(f, "[{:^5.*}]", 18, 1.5_f32).unwrap();
+    f.set_overflow(Overflow::Saturate);
+    uwrite!(f, "[{:.*}][{:.*e}]", 99, 0.1_f64, 18, 0.1_f64).unwrap();
+    assert_eq!("[  -  ][0.10000000000000001][1.00000000000000006e-1]", s);
+
+    let mut w = Checked(String::new());
+    assert_eq!(Ok(()), uwrite!(&mut w, "{:.3}", 1.5_f32));
+    assert_eq!(
+        Err(CheckedError::Overflow),
+        uwrite!(&mut w, " {:.*}", 18, 1.5_f32)
+    );
+    assert_eq!("1.500 ", w.0);
+}
+
+#[test]
+fn high_precision() {
+    cmp!("{:.7}", 3.14159265358979_f64);
+    cmp!("{:.9}", 3.14159265358979_f32);
+    cmp!("{:.15}", 52.520008_f64);
+    cmp!("{:.17}", -13.404954_f64);
+    cmp!("{:.17}", 0.1_f32);
+    cmp!("{:.17}", f64::EPSILON);
+    cmp!("{:.17}", f64::MIN_POSITIVE);
+    cmp!("{:.12}", 0.000_000_000_000_5_f64);
+    cmp!("{:.12}", 0.000_000_000_000_500_1_f64);
+    cmp!("{:.8}", 0.999_999_999_f64);
+    cmp!("{:.10}", 1.5e30_f64);
+    cmp!("{:.17}", f64::MAX);
+    cmp!("{:.17}", f32::MIN);
+    cmp!("{:30.12}", 2.5_f64);
+    cmp!("{:<30.12}", -2.5_f32);
+    cmp!("{:_^30.16}", 1e-3_f64);
+    cmp!("{:.*}", 17, 4_294_967_295.123_f64);
+
+    let mut f = 1e-12_f64;
+    while f < 1e12 {
+        for prec in 7..18 {
+            cmp!("{:.*}", prec, f);
+            if f >= 1e-7 {
+                // smaller negative numbers may be displayed as zero, see `negative_zero()`
+                cmp!("{:.*}", prec, -f);
+            }
+            cmp!("{:.*}", prec, f as f32);
+        }
+        f *= 5.9;
+    }
+}
+
+#[test]
+fn ties() {
+    cmp!("{:.0}", 0.5_f64);
+    cmp!("{:.0}", 1.5_f64);
+    cmp!("{:.0}", 2.5_f64);
+    cmp!("{:.0}", -2.5_f32);
+    cmp!("{:.1}", 0.25_f32);
+    cmp!("{:.2}", 0.125_f64);
+    cmp!("{:.2}", -0.375_f32);
+    cmp!("{:8.3}", 1.0625_f64);
+
+    // all multiples of 2^-7 are ties or exact with up to 6 decimal places
+    for i in 0..1024 {
+        let f = i as f64 / 128.0;
+        for prec in 0..7 {
+            cmp!("{:.*}", prec, f);
+            #[cfg(not(feature = "no_negative_zero"))]
+            cmp!("{:.*}", prec, -f as f32);
+        }
+    }
+}
+
+#[test]
+fn full_range() {
+    cmp!("{:.0}", f32::MAX);
+    cmp!("{:.6}", f32::MIN);
+    cmp!("{:.0}", f64::MAX);
+    cmp!("{:.6}", f64::MIN);
+    cmp!("{:.3}", -1e300_f64);
+    cmp_str!("100000002004087734272.000", "{}", 1e20_f32); // std::format "100000000000000000000"
+    cmp_str!("123456789012.500", "{:?}", 123_456_789_012.5_f64); // std::format "123456789012.5"
+    cmp!("{:.0}", 16_777_217.5_f64);
+    cmp!("{:.0}", 9_999_999_999.5_f64);
+    cmp!("{:.2}", 9_999_999_999.995_f64);
+    cmp!("{:40.1}", 1.5e30_f64);
+    cmp!("{:<40.1}", -1.5e30_f32);
+    cmp!("{:^40.3}", 4.2e25_f64);
+    cmp!("{:.3}", 2.0_f32.powi(100));
+    cmp!("{:.3}", 2.0_f64.powi(1000));
+
+    let mut f = 1e6_f64;
+    while f < 1e308 {
+        for prec in 0..7 {
+            cmp!("{:.*}", prec, f);
+            cmp!("{:.*}", prec, -f);
+            if f < f32::MAX as f64 {
+                cmp!("{:.*}", prec, f as f32);
+            }
+        }
+        f *= 7.3;
+    }
+}
+
+#[test]
+fn exp() {
+    cmp!("{:e}", 0.0_f32);
+    cmp!("{:e}", 1.0_f32);
+    cmp!("{:e}", 0.1_f32);
+    cmp!("{:e}", 0.1_f64);
+    cmp!("{:e}", 1e-7_f64);
+    cmp!("{:e}", 1234.5_f32);
+    cmp!("{:e}", -1234.5_f64);
+    cmp!("{:e}", 1e12_f64);
+    cmp!("{:E}", 6.02214076e23_f64);
+    cmp!("{:e}", 5e-324_f64);
+    cmp!("{:e}", f32::NAN);
+    cmp!("{:e}", f64::INFINITY);
+    cmp!("{:e}", f64::NEG_INFINITY);
+
+    cmp!("{:e}", f32::MAX);
+    cmp!("{:e}", f64::MAX);
+    cmp!("{:e}", 16_777_216_f32);
+    cmp!("{:e}", f32::MIN_POSITIVE);
+
+    cmp!("{:.0e}", 2.5_f64);
+    cmp!("{:.0e}", 3.5_f64);
+    cmp!("{:.0e}", 9.5_f32);
+    cmp!("{:.2e}", 9.999_f64);
+    cmp!("{:.3e}", 0.0_f64);
+    cmp!("{:.3E}", -0.000_012_345_f64);
+    cmp!("{:.17e}", 0.1_f64);
+    cmp!("{:.17e}", f64::MAX);
+    cmp!("{:.17e}", 5e-324_f64);
+    cmp!("{:.8e}", f32::MAX);
+    cmp!("{:.8e}", f32::MIN_POSITIVE);
+
+    cmp!("{:12.2e}", 3.14159_f32);
+    cmp!("{:<12.2e}", -3.14159_f64);
+    cmp!("{:^12.2E}", 3.14159_f64);
+    cmp!("{:_>12e}", 1500.0_f64);
+    cmp!("{:.*e}", 4, 2.71828_f64);
+    cmp!("{:w$.p$e}", 2.71828_f32, w = 12, p = 3);
+
+    // without precision the shortest digits, powers of two are the boundary cases
+    for e in -149..128 {
+        cmp!("{:e}", 2.0_f32.powi(e));
+    }
+    for e in -1074..1024 {
+        cmp!("{:E}", 2.0_f64.powi(e));
+    }
+
+    let mut f = 1e-9_f64;
+    while f < 1e12 {
+        for prec in 0..8 {
+            cmp!("{:.*e}", prec, f);
+            cmp!("{:.*e}", prec, -f);
+            cmp!("{:.*e}", prec, f as f32);
+        }
+        f *= 3.7;
+    }
+}
+
+// `{:r}` has no counterpart in `core::fmt`, it must match the output of `{}`
+macro_rules! cmp_shortest {
+    ($f:expr) => {
+        assert_eq!(
+            efmt::uformat!(500, "{:r}", $f).unwrap().as_str(),
+            format!("{}", $f).as_str(),
+        )
+    };
+}
+
+#[test]
+fn shortest() {
+    cmp_shortest!(0.0_f32);
+    cmp_shortest!(1.0_f32);
+    cmp_shortest!(0.1_f32);
+    cmp_shortest!(0.1_f64);
+    cmp_shortest!(0.3_f64);
+    cmp_shortest!(1e-5_f32);
+    cmp_shortest!(-1234.5_f64);
+    cmp_shortest!(1e23_f64);
+    cmp_shortest!(f32::MAX);
+    cmp_shortest!(f32::MIN_POSITIVE);
+    cmp_shortest!(f32::EPSILON);
+    cmp_shortest!(1e-45_f32);
+    cmp_shortest!(f64::MAX);
+    cmp_shortest!(f64::MIN);
+    cmp_shortest!(f64::MIN_POSITIVE);
+    cmp_shortest!(f64::EPSILON);
+    cmp_shortest!(5e-324_f64);
+    cmp_shortest!(f32::NAN);
+    cmp_shortest!(f64::INFINITY);
+    cmp_shortest!(f64::NEG_INFINITY);
+
+    cmp_str!("      0.25", "{:10r}", 0.25_f32);
+    cmp_str!("-1.5______", "{:_<10r}", -1.5_f64);
+    cmp_str!("  0.001   ", "{:^w$r}", 0.001_f64, w = 10);
+
+    // pseudo random bit patterns covering the whole range
+    let mut x = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..10_000 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let f = f64::from_bits(x);
+        if f.is_finite() {
+            cmp_shortest!(f);
+        }
+        let f = f32::from_bits((x >> 32) as u32);
+        if f.is_finite() {
+            cmp_shortest!(f);
+        }
+    }
+
+    // boundary cases: powers of two, where the lower neighbour is closer
+    for e in -149..128 {
+        cmp_shortest!(2.0_f32.powi(e));
+    }
+    for e in -1074..1024 {
+        cmp_shortest!(2.0_f64.powi(e));
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::legacy_numeric_constants)]
+
 mod macros;
 
 #[test]
@@ -85,6 +87,22 @@ fn uxx_hex() {
     cmp!("{:#x}", 0_usize);
 }
 
+#[test]
+fn oct_bin() {
+    cmp!("{:b}", u64::MAX);
+    cmp!("{:#b}", u128::MAX);
+    cmp!("{:#o}", u128::MAX);
+    cmp!("{:b}", i64::MIN);
+    cmp!("{:#b}", 0_u8);
+    cmp!("{:12b}", 5_u8);
+    cmp!("{:<#12o}", 64_u16);
+
+    // zero padding beyond the size of the conversion buffer
+    cmp!("{:040x}", 235_u8);
+    cmp!("{:#0140b}", u128::MAX);
+    cmp!("{:^#050X}", 235_u32);
+}
+
 #[test]
 fn ixx_min_max() {
     cmp!("{}", i8::min_value());
@@ -168,6 +186,152 @@ fn ixx_hex() {
 }
 
 #[test]
+fn runtime_width() {
+    for width in [0, 1, 3, 5, 10] {
+        cmp!("{:1$}", 235_u8, width);
+        cmp!("{:<width$}", -115_i32);
+        cmp!("{:^w$}", 4711_u64, w = width);
+        cmp!("{:0>w$}", 4711_u32, w = width);
+        cmp!("{:w$x}", 235_u16, w = width);
+        cmp!("{:#0w$x}", 235_u16, w = width);
+        cmp!("{0:>1$} {0:<1$}", 42_i16, width);
+    }
+}
+
+#[test]
+fn sign_plus() {
+    for i in [0_i32, 7, -7, 4711, -4711, i32::MAX, i32::MIN] {
+        cmp!("{:+}", i);
+        cmp!("{:+5}", i);
+        cmp!("{:<+6}", i);
+        cmp!("{:^+7}", i);
+        cmp!("{:_>+7}", i);
+        cmp!("{:+08}", i);
+    }
+    cmp!("{:+}", u64::MAX);
+    cmp!("{:+x}", 235_u8);
+    cmp!("{:+#X}", 235_u16);
+    cmp!("{:+#010x}", 235_u32);
+    cmp!("{:+010b}", 5_u8);
+    cmp!("{:>+8o}", 8_u64);
+    cmp!("{:+<5}", 42_u8);
+}
+
+#[test]
+fn zero_padding() {
+    for i in [0_i64, 7, -7, 4711, -4711, i64::MAX, i64::MIN] {
+        cmp!("{:08}", i);
+        cmp!("{:020}", i);
+        cmp!("{:01}", i);
+        cmp!("{:<08}", i);
+        cmp!("{:^08}", i);
+        cmp!("{:>08}", i);
+        cmp!("{:0>8}", i);
+        cmp!("{:0<8}", i);
+        cmp!("{:0^9}", i);
+        cmp!("{:08}", i as i8);
+        cmp!("{:08}", i as i16);
+        cmp!("{:08}", i as i32);
+        cmp!("{:08}", i as i128);
+        cmp!("{:08}", i as isize);
+        cmp!("{:08x}", i);
+        cmp!("{:#010x}", i as i32);
+        cmp!("{:<#010X}", i as i16);
+        cmp!("{:#06b}", i as i8);
+        cmp!("{:0>#10x}", i as u16);
+        cmp!("{:#0w$o}", i as u32, w = 12);
+    }
+}
+
+#[test]
+fn byte_buffer() {
+    let buf = [0x01_u8, 0xab, 0x00, 0xff];
+    cmp_str!("01ab00ff", "{:x}", buf);
+    cmp_str!("01AB00FF", "{:X}", buf);
+    cmp_str!("0x01ab00ff", "{:#x}", buf);
+    cmp_str!("0o001253000377", "{:#o}", buf);
+    cmp_str!("00000001101010110000000011111111", "{:b}", buf);
+    cmp_str!("  01ab00ff", "{:10x}", buf);
+    cmp_str!("01ab00ff__", "{:_<10x}", buf);
+    cmp_str!("0x0001ab00ff", "{:#012x}", buf);
+    cmp_str!("", "{:x}", [0_u8; 0]);
+
+    let frame = &buf[1..3];
+    cmp_str!("ab00", "{:x}", frame);
+    cmp_str!("0xAB00", "{:#X}", &buf[1..3]);
+    cmp_str!("  ab00  ", "{:^8x}", frame);
+
+    let mac = efmt::HexBytes::new(&buf).separator(":");
+    cmp_str!("01:ab:00:ff", "{:x}", mac);
+    cmp_str!(
+        "01 AB 00 FF",
+        "{:X}",
+        efmt::HexBytes::new(&buf).separator(" ")
+    );
+    cmp_str!("0x01:ab:00:ff", "{:#x}", mac);
+    cmp_str!("  01:ab:00:ff|", "{:13x}|", mac);
+    cmp_str!("00001:ab:00:ff|", "{:014x}|", mac);
+    cmp_str!("", "{:x}", efmt::HexBytes::new(&[]).separator(":"));
+    cmp_str!("01", "{:x}", efmt::HexBytes::new(&buf[..1]).separator(":"));
+
+    let dma = [0x5a_u8; 512];
+    let s = efmt::uformat!(2000, "{:X}", dma).unwrap();
+    assert_eq!(1024, s.len());
+    assert!(s.as_str().bytes().all(|c| c == b'5' || c == b'A'));
+}
+
+#[test]
+#[cfg(feature = "heapless08")]
+fn byte_buffer_heapless() {
+    let mut v = heapless08::Vec::<u8, 8>::new();
+    v.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+    cmp_str!("deadbeef", "{:x}", v);
+    cmp_str!("0xDEADBEEF", "{:#X}", v);
+}
+
+#[test]
+fn hexdump() {
+    let frame = *b"Hello, efmt!\x00\x01\x02\x7f\xffhexdump -C";
+    cmp_str!(
+        "00000000  48 65 6c 6c 6f 2c 20 65  66 6d 74 21 00 01 02 7f  |Hello, efmt!....|
+00000010  ff 68 65 78 64 75 6d 70  20 2d 43                 |.hexdump -C|
+0000001b",
+        "{:#x?}",
+        frame
+    );
+    cmp_str!(
+        "00000000  48 65 6C 6C 6F                                    |Hello|
+00000005",
+        "{:#X?}",
+        &frame[..5]
+    );
+    cmp_str!(
+        "00000000  41 42 43 44 45 46 47 48  49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|
+00000010",
+        "{:#x?}",
+        efmt::HexBytes::new(b"ABCDEFGHIJKLMNOP")
+    );
+    cmp!("{:#x?}", [0_u8; 0]);
+    cmp!("{:#X?}", &frame[..0]);
+    cmp!("{:?}", &frame[..3]);
+    cmp!("{:#?}", &frame[..3]);
+
+    // nested bytes are a list, the lines of the hex dump would break the indentation
+    #[derive(Debug, efmt::derive::uDebug)]
+    struct Frame<'a> {
+        header: [u8; 3],
+        payload: &'a [u8],
+    }
+    let nested = Frame {
+        header: [1, 2, 0xff],
+        payload: &frame[..5],
+    };
+    cmp!("{:#x?}", nested);
+    cmp!("{:#X?}", (&frame[..2], Some([0x7f_u8])));
+}
+
+#[test]
+#[allow(clippy::manual_dangling_ptr)]
 fn ptr() {
     cmp!("{:?}", 1 as *const u8);
     cmp!("{:?}", 0xf as *const u8);
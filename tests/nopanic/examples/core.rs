@@ -6,13 +6,19 @@ use efmt::uwrite;
 use common::W;
 
 #[no_mangle]
-fn _start(s: &str, c: char) {
+fn _start(s: &str, c: char, w: usize) {
     uwrite!(&mut W, "{}", s).unwrap();
     uwrite!(&mut W, "{:20}", s).unwrap();
     uwrite!(&mut W, "{:<20}", s).unwrap();
     uwrite!(&mut W, "{:>20}", s).unwrap();
     uwrite!(&mut W, "{:^20}", s).unwrap();
     uwrite!(&mut W, "{:0^20}", s).unwrap();
+    uwrite!(&mut W, "{:.3}", s).unwrap();
+    uwrite!(&mut W, "{:8.3}", s).unwrap();
+    uwrite!(&mut W, "{:#8.3}", s).unwrap();
+    uwrite!(&mut W, "{:#8.w$}", s).unwrap();
+    uwrite!(&mut W, "{:?}", s).unwrap();
+    uwrite!(&mut W, "{:#?}", s).unwrap();
 
     uwrite!(&mut W, "{}", c).unwrap();
     uwrite!(&mut W, "{:20}", c).unwrap();
@@ -20,4 +26,5 @@ fn _start(s: &str, c: char) {
     uwrite!(&mut W, "{:>20}", c).unwrap();
     uwrite!(&mut W, "{:^20}", c).unwrap();
     uwrite!(&mut W, "{:0^20}", c).unwrap();
+    uwrite!(&mut W, "{:?}", c).unwrap();
 }
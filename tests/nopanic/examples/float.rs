@@ -18,4 +18,12 @@ fn _start(a: f32, b: f64) {
     uwrite!(&mut W, "{:^10.2}", a).unwrap();
     uwrite!(&mut W, "{:^10.2}", b).unwrap();
     uwrite!(&mut W, "{:0^10.2}", b).unwrap();
+    uwrite!(&mut W, "{:e}", a).unwrap();
+    uwrite!(&mut W, "{:e}", b).unwrap();
+    uwrite!(&mut W, "{:10.3E}", a).unwrap();
+    uwrite!(&mut W, "{:10.3E}", b).unwrap();
+    uwrite!(&mut W, "{:r}", a).unwrap();
+    uwrite!(&mut W, "{:>10r}", b).unwrap();
+    uwrite!(&mut W, "{:+}", a).unwrap();
+    uwrite!(&mut W, "{:08}", b).unwrap();
 }
@@ -84,3 +84,13 @@ fn _start2(a: i8, b: i16, c: i32, d: isize, e: i64, f: i128) {
 
     uwrite!(&mut W, "{:0^20}", f).unwrap();
 }
+
+#[no_mangle]
+fn _start3(a: &[u8], sep: &str) {
+    uwrite!(&mut W, "{:x}", a).unwrap();
+    uwrite!(&mut W, "{:#20X}", a).unwrap();
+    uwrite!(&mut W, "{:x}", efmt::HexBytes::new(a).separator(sep)).unwrap();
+    uwrite!(&mut W, "{:?}", a).unwrap();
+    uwrite!(&mut W, "{:#x?}", a).unwrap();
+    uwrite!(&mut W, "{:#X?}", efmt::HexBytes::new(a)).unwrap();
+}